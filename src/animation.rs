@@ -0,0 +1,449 @@
+//! Export of a simulation as an animation, e.g. the robots of day 14 over a range of seconds, with
+//! `aoc run --day NN --animate <PATH> --frames <START>..<END>`.
+//!
+//! A [`Frame`] is a grid of intensities, 0 for an empty cell and more for a busier one (e.g. the
+//! number of robots on it), so any grid day can produce them. The format is picked from the
+//! extension of the path:
+//!
+//! - `.gif`: a single animated GIF.
+//! - `.pbm` or `.pgm`: one image per frame, named after the path and the step, e.g.
+//!   `frames/robots.pgm` gives `frames/robots_00042.pgm`. PBM only tells empty cells from busy
+//!   ones, PGM keeps the intensity as a grey level.
+//! - `.txt`: a flipbook of ASCII frames, `.` for empty cells and `*` for busy ones, unless the day
+//!   draws its frames itself (see [`Frame::with_text`]).
+//!
+//! A day can also be stepped through interactively with `aoc replay`, through [`Replay`].
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use ahash::{HashMap, HashMapExt};
+use glam::IVec2;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Gif,
+    Pbm,
+    Pgm,
+    Flipbook,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "gif" => Some(Format::Gif),
+            "pbm" => Some(Format::Pbm),
+            "pgm" => Some(Format::Pgm),
+            "txt" => Some(Format::Flipbook),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// The step of the simulation, e.g. the second of day 14.
+    pub step: usize,
+    pub grid: Grid<u8>,
+    /// The frame as drawn by the day, for the flipbook.
+    pub text: Option<String>,
+}
+
+impl Frame {
+    /// A frame where each cell counts the points on it.
+    pub fn from_points(
+        step: usize,
+        width: usize,
+        height: usize,
+        points: impl IntoIterator<Item = IVec2>,
+    ) -> Self {
+        let mut grid = Grid::new(width, height, 0_u8);
+        points.into_iter().for_each(|point| {
+            if let Some(cell) = grid.get_mut(point) {
+                *cell = cell.saturating_add(1);
+            }
+        });
+        Self {
+            step,
+            grid,
+            text: None,
+        }
+    }
+
+    pub fn with_text(self, text: String) -> Self {
+        Self {
+            text: Some(text),
+            ..self
+        }
+    }
+
+    /// Ignores [`Frame::text`], see [`Frame::to_text`] for the flipbook.
+    pub fn to_ascii(&self) -> String {
+        self.grid
+            .map(|&cell| if cell == 0 { '.' } else { '*' })
+            .to_string()
+    }
+
+    pub fn to_text(&self) -> String {
+        self.text.clone().unwrap_or_else(|| self.to_ascii())
+    }
+
+    /// Binary PBM, with busy cells in black.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut image = format!("P4\n{} {}\n", self.grid.width(), self.grid.height()).into_bytes();
+        self.grid.rows().for_each(|row| {
+            row.chunks(8).for_each(|chunk| {
+                let byte = chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, cell)| **cell > 0)
+                    .fold(0_u8, |byte, (idx, _)| byte | (0x80 >> idx));
+                image.push(byte);
+            });
+        });
+        image
+    }
+
+    /// Binary PGM, with busy cells in white and the busiest one at full brightness.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let max = self
+            .grid
+            .iter()
+            .map(|(_, cell)| *cell)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let mut image = format!(
+            "P5\n{} {}\n{}\n",
+            self.grid.width(),
+            self.grid.height(),
+            max
+        )
+        .into_bytes();
+        image.extend(self.grid.rows().flatten());
+        image
+    }
+}
+
+/// Writes `frames` to `path` in `format`, and returns how many were written.
+pub fn save(
+    path: &Path,
+    format: Format,
+    frames: impl IntoIterator<Item = Frame>,
+) -> io::Result<usize> {
+    let mut frames = frames.into_iter().peekable();
+    let mut count = 0;
+
+    match format {
+        Format::Gif => {
+            let Some(first) = frames.peek() else {
+                return Err(io::Error::other("no frames to animate"));
+            };
+            let mut gif = Gif::new(
+                BufWriter::new(File::create(path)?),
+                first.grid.width(),
+                first.grid.height(),
+            )?;
+            for frame in frames {
+                gif.add_frame(&frame)?;
+                count += 1;
+            }
+            gif.finish()?;
+        }
+        Format::Pbm | Format::Pgm => {
+            for frame in frames {
+                let image = match format {
+                    Format::Pbm => frame.to_pbm(),
+                    _ => frame.to_pgm(),
+                };
+                std::fs::write(frame_path(path, frame.step), image)?;
+                count += 1;
+            }
+        }
+        Format::Flipbook => {
+            let mut file = BufWriter::new(File::create(path)?);
+            for frame in frames {
+                writeln!(file, "step {}\n{}\n", frame.step, frame.to_text())?;
+                count += 1;
+            }
+            file.flush()?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// A simulation that can be stepped through.
+pub trait Replay {
+    /// Advances by one step, and describes what happened. Returns `None` at the end.
+    fn step(&mut self) -> Option<String>;
+
+    fn render(&self) -> String;
+}
+
+/// The path of one image of a sequence, e.g. `frames/robots_00042.pgm` for `frames/robots.pgm`.
+pub fn frame_path(path: &Path, step: usize) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+    let extension = path
+        .extension()
+        .map_or_else(String::new, |ext| ext.to_string_lossy().into_owned());
+    path.with_file_name(format!("{}_{:05}.{}", stem, step, extension))
+}
+
+/// The palette of the GIF: black for empty cells, and brighter greens for busier ones.
+const PALETTE: [[u8; 3]; 4] = [[0, 0, 0], [0, 120, 0], [0, 190, 0], [120, 255, 120]];
+/// Bits per pixel, which is also the minimum LZW code size.
+const COLOR_BITS: u8 = 2;
+/// Delay between frames, in hundredths of a second.
+const FRAME_DELAY: u16 = 10;
+
+struct Gif<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write> Gif<W> {
+    fn new(mut writer: W, width: usize, height: usize) -> io::Result<Self> {
+        let too_large = || io::Error::other("the frames are too large for a GIF");
+        let width = u16::try_from(width).map_err(|_| too_large())?;
+        let height = u16::try_from(height).map_err(|_| too_large())?;
+
+        writer.write_all(b"GIF89a")?;
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        // global color table of 2^COLOR_BITS colors, with 8 bits per primary
+        writer.write_all(&[0xF0 | (COLOR_BITS - 1), 0, 0])?;
+        writer.write_all(PALETTE.as_flattened())?;
+        // NETSCAPE2.0 extension, so that viewers loop forever
+        writer.write_all(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        Ok(Self {
+            writer,
+            width,
+            height,
+        })
+    }
+
+    fn add_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if (frame.grid.width(), frame.grid.height()) != (self.width as usize, self.height as usize)
+        {
+            return Err(io::Error::other("the frames do not all have the same size"));
+        }
+
+        // graphic control extension with the delay, then the image descriptor
+        self.writer.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+        self.writer.write_all(&FRAME_DELAY.to_le_bytes())?;
+        self.writer.write_all(&[0x00, 0x00, 0x2C, 0, 0, 0, 0])?;
+        self.writer.write_all(&self.width.to_le_bytes())?;
+        self.writer.write_all(&self.height.to_le_bytes())?;
+        self.writer.write_all(&[0x00, COLOR_BITS])?;
+
+        let max_color = PALETTE.len() as u8 - 1;
+        let pixels = frame
+            .grid
+            .rows()
+            .flatten()
+            .map(|cell| (*cell).min(max_color))
+            .collect::<Vec<_>>();
+        lzw_encode(&pixels, COLOR_BITS)
+            .chunks(255)
+            .try_for_each(|block| {
+                self.writer.write_all(&[block.len() as u8])?;
+                self.writer.write_all(block)
+            })?;
+        self.writer.write_all(&[0x00])
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        self.writer.write_all(&[0x3B])?;
+        self.writer.flush()
+    }
+}
+
+/// Variable length LZW as used by GIF, with codes packed from the least significant bit.
+fn lzw_encode(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    const MAX_CODES: u16 = 1 << 12;
+    let clear = 1_u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut output = vec![];
+    let (mut buffer, mut bits) = (0_u32, 0_u32);
+    let mut emit = |code: u16, size: u8| {
+        buffer |= (code as u32) << bits;
+        bits += size as u32;
+        while bits >= 8 {
+            output.push(buffer as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    };
+
+    let mut table = HashMap::new();
+    let mut size = min_code_size + 1;
+    let mut next = end + 1;
+    let mut prefix = None;
+    emit(clear, size);
+
+    for &pixel in pixels {
+        let Some(code) = prefix else {
+            prefix = Some(pixel as u16);
+            continue;
+        };
+        if let Some(&longer) = table.get(&(code, pixel)) {
+            prefix = Some(longer);
+            continue;
+        }
+
+        emit(code, size);
+        if next < MAX_CODES {
+            table.insert((code, pixel), next);
+            next += 1;
+            // the decoder adds its codes one step later, so it widens them when `next` is past
+            // the current size rather than equal to it
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        } else {
+            emit(clear, size);
+            table.clear();
+            size = min_code_size + 1;
+            next = end + 1;
+        }
+        prefix = Some(pixel as u16);
+    }
+
+    if let Some(code) = prefix {
+        emit(code, size);
+    }
+    emit(end, size);
+    // flush the last partial byte
+    emit(0, 7);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+
+    fn frame(step: usize, points: &[(i32, i32)]) -> Frame {
+        let points = points.iter().map(|&(x, y)| IVec2::new(x, y));
+        Frame::from_points(step, 10, 3, points)
+    }
+
+    /// GIF's LZW decoder, written from the specification rather than from the encoder.
+    fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1_usize << min_code_size;
+        let end = clear + 1;
+        let reset = || -> Vec<Vec<u8>> {
+            (0..clear as u8)
+                .map(|i| vec![i])
+                .chain([vec![], vec![]])
+                .collect()
+        };
+
+        let mut table = reset();
+        let mut size = min_code_size as usize + 1;
+        let mut previous: Option<usize> = None;
+        let mut output = vec![];
+        let mut bit = 0;
+
+        loop {
+            let code = (0..size)
+                .map(|i| ((data[(bit + i) / 8] >> ((bit + i) % 8)) & 1) as usize)
+                .enumerate()
+                .fold(0, |code, (i, value)| code | (value << i));
+            bit += size;
+
+            if code == clear {
+                table = reset();
+                size = min_code_size as usize + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return output;
+            }
+
+            let entry = match (table.get(code), previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => {
+                    let mut entry = table[previous].clone();
+                    entry.push(table[previous][0]);
+                    entry
+                }
+                (None, None) => panic!("invalid code {}", code),
+            };
+            if let Some(previous) = previous {
+                if table.len() < 4096 {
+                    let mut added = table[previous].clone();
+                    added.push(entry[0]);
+                    table.push(added);
+                }
+            }
+            if table.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+            output.extend(&entry);
+            previous = Some(code);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut rng = XorShift::new(7);
+        let noise = (0..20_000).map(|_| rng.below(4) as u8).collect::<Vec<_>>();
+        let runs = (0..20_000).map(|i| (i / 300 % 4) as u8).collect::<Vec<_>>();
+
+        for pixels in [vec![], vec![3], noise, runs] {
+            assert_eq!(lzw_decode(&lzw_encode(&pixels, 2), 2), pixels);
+        }
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = frame(3, &[(0, 0), (9, 0), (9, 0), (2, 2), (10, 0), (-1, 1)]);
+        assert_eq!(frame.to_ascii(), "*........*\n..........\n..*.......");
+        assert_eq!(
+            frame.to_pbm(),
+            b"P4\n10 3\n\x80\x40\x00\x00\x20\x00".to_vec()
+        );
+
+        let pgm = frame.to_pgm();
+        assert!(pgm.starts_with(b"P5\n10 3\n2\n"));
+        assert_eq!(pgm[pgm.len() - 30..][..10], [1, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join("aoc_2024_animation");
+        std::fs::create_dir_all(&dir).unwrap();
+        let frames = || (0..3).map(|step| frame(step, &[(step as i32, 1)]));
+
+        let gif = dir.join("robots.gif");
+        assert_eq!(save(&gif, Format::Gif, frames()).unwrap(), 3);
+        let gif = std::fs::read(gif).unwrap();
+        assert!(gif.starts_with(b"GIF89a\x0a\x00\x03\x00"));
+        assert_eq!(gif.last(), Some(&0x3B));
+
+        let pgm = dir.join("robots.pgm");
+        assert_eq!(save(&pgm, Format::Pgm, frames()).unwrap(), 3);
+        assert!(dir.join("robots_00002.pgm").exists());
+
+        let flipbook = dir.join("robots.txt");
+        save(&flipbook, Format::Flipbook, frames()).unwrap();
+        let flipbook = std::fs::read_to_string(flipbook).unwrap();
+        assert!(flipbook.starts_with("step 0\n..........\n*.........\n"));
+        assert!(flipbook.contains("step 2\n"));
+
+        assert!(save(&dir.join("empty.gif"), Format::Gif, []).is_err());
+    }
+}
@@ -0,0 +1,424 @@
+//! The manifest of known-good answers for the actual inputs, used by `aoc verify` and the
+//! `*_actual` tests.
+//!
+//! The manifest is `answers.toml` at the root of the repository, with one table per day keyed by
+//! year and day, and one key per part:
+//!
+//! ```toml
+//! [2024.01]
+//! part1 = "1882714"
+//! part2 = "19437052"
+//! ```
+//!
+//! The answers of other input profiles (see [`crate::input`]) go in tables with the profile name
+//! as a third key, e.g. `[2024.01.alice]`. A table without a profile is for the default profile.
+//!
+//! Only this subset of TOML is understood. A part that is not in the manifest is reported as
+//! missing rather than failing.
+
+use std::{collections::BTreeMap, fmt::Display, io, path::PathBuf};
+
+use crate::{
+    input::{InputSource, DEFAULT_PROFILE},
+    parse, DynSolution, ParseError,
+};
+
+pub const MANIFEST_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    pub year: u32,
+    pub day: u8,
+    pub profile: String,
+    pub part: u8,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Malformed {
+        path: PathBuf,
+        source: Box<ParseError>,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Unreadable { path, source } => {
+                write!(f, "cannot read answers {}: {}", path.display(), source)
+            }
+            AnswersError::Malformed { path, source } => write!(
+                f,
+                "malformed answers {}, {}",
+                path.display(),
+                source.diagnostic()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Unreadable { source, .. } => Some(source),
+            AnswersError::Malformed { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+pub fn manifest_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(MANIFEST_FILE)
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        let mut table = None;
+
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| ParseError::new(line, "expected a table like [2024.01]"))?;
+                let (year, rest) = parse::split_once(header, ".")?;
+                let (day, profile) = rest.split_once('.').unwrap_or((rest, DEFAULT_PROFILE));
+                if profile.is_empty() {
+                    return Err(ParseError::new(header, "expected a profile name"));
+                }
+                table = Some((parse::number(year)?, parse::number(day)?, profile));
+                continue;
+            }
+
+            let (year, day, profile) = table.ok_or_else(|| {
+                ParseError::new(line, "expected a table like [2024.01] before the answers")
+            })?;
+            let (name, value) = parse::split_once(line, "=")?;
+            let part = match name.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                name => return Err(ParseError::new(name, "expected part1 or part2")),
+            };
+            let value = value.trim();
+            let answer = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .ok_or_else(|| ParseError::new(value, "expected a quoted answer"))?;
+
+            let key = Key {
+                year,
+                day,
+                profile: profile.to_string(),
+                part,
+            };
+            answers.insert(key, answer.to_string());
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Loads the manifest at [`manifest_path`].
+    pub fn load() -> Result<Self, AnswersError> {
+        let path = manifest_path();
+        let input = std::fs::read_to_string(&path).map_err(|source| AnswersError::Unreadable {
+            path: path.clone(),
+            source,
+        })?;
+
+        Self::parse(&input).map_err(|source| AnswersError::Malformed {
+            source: Box::new(source.locate(&input)),
+            path,
+        })
+    }
+
+    pub fn get(&self, profile: &str, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .get(&Key {
+                year: crate::input::YEAR,
+                day,
+                profile: profile.to_string(),
+                part,
+            })
+            .map(String::as_str)
+    }
+
+    /// The profiles with an answer for `day`, sorted by name.
+    pub fn profiles(&self, day: u8) -> Vec<&str> {
+        let mut profiles = self
+            .answers
+            .keys()
+            .filter(|key| key.year == crate::input::YEAR && key.day == day)
+            .map(|key| key.profile.as_str())
+            .collect::<Vec<_>>();
+        profiles.dedup();
+        profiles
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The input or the answer is not available.
+    Missing(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Status::Missing(reason) => write!(f, "missing ({})", reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub profile: String,
+    pub part: u8,
+    pub status: Status,
+}
+
+/// Runs both parts of `solution` on its input from `source`, and compares them to the answers of
+/// `profile`.
+pub fn verify(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    profile: &str,
+    answers: &Answers,
+) -> Vec<Check> {
+    let day = solution.day();
+    let parts = if solution.has_part2() {
+        vec![1, 2]
+    } else {
+        vec![1]
+    };
+    let check = |part, status| Check {
+        day,
+        profile: profile.to_string(),
+        part,
+        status,
+    };
+
+    let input = match source.read(day) {
+        Ok(input) => input,
+        Err(_) => {
+            return parts
+                .into_iter()
+                .map(|part| check(part, Status::Missing("no input".to_string())))
+                .collect()
+        }
+    };
+    let parsed = match solution.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            let actual = format!("a parse error: {}", err);
+            return parts
+                .into_iter()
+                .map(|part| {
+                    let status = match answers.get(profile, day, part) {
+                        Some(expected) => Status::Fail {
+                            expected: expected.to_string(),
+                            actual: actual.clone(),
+                        },
+                        None => Status::Missing("no answer".to_string()),
+                    };
+                    check(part, status)
+                })
+                .collect();
+        }
+    };
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let Some(expected) = answers.get(profile, day, part) else {
+                return check(part, Status::Missing("no answer".to_string()));
+            };
+            if part == 2 && !solution.prints_part2() {
+                let reason = "not printed by the solution".to_string();
+                return check(part, Status::Missing(reason));
+            }
+            let actual = match part {
+                1 => parsed.part1(),
+                _ => parsed.part2(),
+            };
+            let status = if actual == expected {
+                Status::Pass
+            } else {
+                Status::Fail {
+                    expected: expected.to_string(),
+                    actual,
+                }
+            };
+            check(part, status)
+        })
+        .collect()
+}
+
+/// Body of a generated `*_actual` test: checks every input profile of the day that has an answer,
+/// and skips if there is none.
+#[cfg(test)]
+pub(crate) fn check_actual(solution: &dyn DynSolution, part: u8) {
+    let answers = Answers::load().unwrap_or_else(|err| panic!("{}", err));
+    let checks = crate::input::profiles(solution.day())
+        .into_iter()
+        .flat_map(|profile| {
+            let source = InputSource::Profile(profile.clone());
+            verify(solution, &source, &profile, &answers)
+        })
+        .filter(|check| check.part == part && !matches!(check.status, Status::Missing(_)))
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        eprintln!(
+            "skipping test: no input with an answer for day {:02} part {}",
+            solution.day(),
+            part
+        );
+    }
+    checks.into_iter().for_each(|check| {
+        assert_eq!(
+            check.status,
+            Status::Pass,
+            "profile {} of day {:02}",
+            check.profile,
+            check.day
+        );
+    });
+}
+
+/// The profile, input and answer of every input profile of `day` with an answer for `part`, for
+/// the tests that check an answer some other way than by running the solution.
+#[cfg(test)]
+pub(crate) fn actual_answers(day: u8, part: u8) -> Vec<(String, String, String)> {
+    let answers = Answers::load().unwrap_or_else(|err| panic!("{}", err));
+    let actual = crate::input::profiles(day)
+        .into_iter()
+        .filter_map(|profile| {
+            let expected = answers.get(&profile, day, part)?.to_string();
+            let input = InputSource::Profile(profile.clone()).read(day).ok()?;
+            Some((profile, input, expected))
+        })
+        .collect::<Vec<_>>();
+
+    if actual.is_empty() {
+        eprintln!(
+            "skipping test: no input with an answer for day {:02} part {}",
+            day, part
+        );
+    }
+    actual
+}
+
+/// Generates `test_p1_actual` and `test_p2_actual` for a solution, checked against the answers
+/// manifest. With `part1_only`, only `test_p1_actual` is generated. With `ignore_part2 = "..."`,
+/// `test_p2_actual` is ignored for that reason.
+#[cfg(test)]
+macro_rules! actual_tests {
+    ($solution:expr) => {
+        $crate::answers::actual_tests!($solution, part1_only);
+
+        #[test]
+        fn test_p2_actual() {
+            $crate::answers::check_actual(&$solution, 2);
+        }
+    };
+    ($solution:expr, ignore_part2 = $reason:literal) => {
+        $crate::answers::actual_tests!($solution, part1_only);
+
+        #[test]
+        #[ignore = $reason]
+        fn test_p2_actual() {
+            $crate::answers::check_actual(&$solution, 2);
+        }
+    };
+    ($solution:expr, part1_only) => {
+        #[test]
+        fn test_p1_actual() {
+            $crate::answers::check_actual(&$solution, 1);
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use actual_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# comment\n[2024.01]\npart1 = \"11\"\n\n[2024.01.alice]\npart1 = \"12\"\n\n[2024.25]\npart1 = \"3\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get("input", 1, 1), Some("11"));
+        assert_eq!(answers.get("input", 1, 2), None);
+        assert_eq!(answers.get("alice", 1, 1), Some("12"));
+        assert_eq!(answers.get("input", 25, 1), Some("3"));
+        assert_eq!(answers.profiles(1), ["alice", "input"]);
+
+        assert!(Answers::parse("part1 = \"11\"").is_err());
+        assert!(Answers::parse("[2024.01]\npart3 = \"11\"").is_err());
+        assert!(Answers::parse("[2024.01]\npart1 = 11").is_err());
+        assert!(Answers::parse("[2024.01.]\npart1 = \"11\"").is_err());
+    }
+
+    #[test]
+    fn test_manifest_is_valid() {
+        let answers = Answers::load().unwrap_or_else(|err| panic!("{}", err));
+        assert_eq!(answers.get("input", 1, 1), Some("1882714"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("[2024.01]\npart1 = \"11\"\npart2 = \"0\"").unwrap();
+        let path = std::env::temp_dir().join("aoc_2024_verify_day01.txt");
+        std::fs::write(&path, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+
+        let checks = verify(
+            crate::solution(1).unwrap(),
+            &InputSource::File(path),
+            "input",
+            &answers,
+        );
+        assert_eq!(checks[0].status, Status::Pass);
+        assert_eq!(
+            checks[1].status,
+            Status::Fail {
+                expected: "0".to_string(),
+                actual: "31".to_string()
+            }
+        );
+
+        let checks = verify(
+            crate::solution(2).unwrap(),
+            &InputSource::File(PathBuf::from("does/not/exist.txt")),
+            "input",
+            &answers,
+        );
+        assert_eq!(checks[0].status, Status::Missing("no input".to_string()));
+    }
+}
@@ -0,0 +1,171 @@
+//! Timing of the parse and both parts of a day, for the `aoc bench` command.
+//!
+//! Every step is repeated a number of times and summarised by its median, min and max, so that a
+//! single slow run (e.g. the first one, with cold caches) does not skew the result. The results
+//! can be saved as JSON, to compare the performance of two commits.
+
+use std::{fmt::Write, time::Duration, time::Instant};
+
+use crate::{DynSolution, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises the samples, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort_unstable();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            r#"{{"median_ns": {}, "min_ns": {}, "max_ns": {}}}"#,
+            self.median.as_nanos(),
+            self.min.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    /// `None` for day 25, which only has one part.
+    pub part2: Option<Stats>,
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        last = Some(result);
+    }
+
+    (
+        Stats::from_samples(samples),
+        last.expect("ran at least once"),
+    )
+}
+
+/// Times the parse and both parts of `solution`, each `runs` times (at least once).
+pub fn bench(solution: &dyn DynSolution, input: &str, runs: usize) -> Result<DayBench, ParseError> {
+    let (parse, parsed) = time(runs, || solution.parse(input));
+    let parsed = parsed?;
+
+    Ok(DayBench {
+        day: solution.day(),
+        parse,
+        part1: time(runs, || parsed.part1()).0,
+        part2: solution
+            .has_part2()
+            .then(|| time(runs, || parsed.part2()).0),
+    })
+}
+
+/// A table with one row per day, and the median (min to max) of each step.
+pub fn table(results: &[DayBench]) -> String {
+    let cell = |stats: Option<Stats>| match stats {
+        Some(stats) => format!(
+            "{:.2?} ({:.2?} to {:.2?})",
+            stats.median, stats.min, stats.max
+        ),
+        None => "-".to_string(),
+    };
+
+    let mut table = format!(
+        "{:<4} {:<32} {:<32} {:<32}",
+        "day", "parse", "part 1", "part 2"
+    );
+    results.iter().for_each(|result| {
+        write!(
+            table,
+            "\n{:<4} {:<32} {:<32} {}",
+            format!("{:02}", result.day),
+            cell(Some(result.parse)),
+            cell(Some(result.part1)),
+            cell(result.part2)
+        )
+        .expect("writing to a string cannot fail");
+    });
+    table
+}
+
+pub fn to_json(results: &[DayBench], runs: usize) -> String {
+    let days = results
+        .iter()
+        .map(|result| {
+            format!(
+                r#"    {{"day": {}, "parse": {}, "part1": {}, "part2": {}}}"#,
+                result.day,
+                result.parse.to_json(),
+                result.part1.to_json(),
+                result
+                    .part2
+                    .map_or_else(|| "null".to_string(), Stats::to_json)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    format!(
+        "{{\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        runs, days
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.median, stats.min, stats.max), (ms(3), ms(1), ms(5)));
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(9)]).median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn test_bench_and_json() {
+        let lock = "#####\n.####\n..###\n...##\n....#\n.....\n.....";
+        let result = bench(crate::solution(25).unwrap(), lock, 3).unwrap();
+        assert_eq!(result.day, 25);
+        assert_eq!(result.part2, None);
+
+        let json = to_json(std::slice::from_ref(&result), 3);
+        assert!(json.starts_with("{\n  \"runs\": 3,"));
+        assert!(json.contains(r#"{"day": 25, "parse": {"median_ns": "#));
+        assert!(json.contains(r#""part2": null}"#));
+        assert!(table(&[result]).lines().nth(1).unwrap().starts_with("25 "));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{parse, ParseError, Solution};
+
+fn read_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    Ok(input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (left, right) = parse::split_once(line.trim(), "   ")?;
+            Ok((parse::number(left)?, parse::number(right)?))
+        })
+        .collect::<Result<Vec<(i32, i32)>, ParseError>>()?
+        .into_iter()
+        .unzip())
+}
+
+fn p1((left_list, right_list): &(Vec<i32>, Vec<i32>)) -> String {
+    let mut left_list = left_list.clone();
+    let mut right_list = right_list.clone();
+
+    left_list.sort_unstable();
+    right_list.sort_unstable();
+
+    left_list
+        .into_iter()
+        .zip(right_list)
+        .map(|(left, right)| (left - right).abs())
+        .sum::<i32>()
+        .to_string()
+}
+
+fn p2((left_list, right_list): &(Vec<i32>, Vec<i32>)) -> String {
+    let right_map = right_list.iter().fold(HashMap::new(), |mut acc, number| {
+        *(acc.entry(*number).or_insert(0)) += 1;
+        acc
+    });
+
+    left_list
+        .iter()
+        .map(|left_number| left_number * (*right_map.get(left_number).unwrap_or(&0)))
+        .sum::<i32>()
+        .to_string()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = (Vec<i32>, Vec<i32>);
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        read_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT: &str = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day01, SAMPLE_INPUT), "11");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day01, SAMPLE_INPUT), "31");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day01, "3   4\n4   x\n"),
+            "line 2, column 5: expected a number, found \"x\""
+        );
+        assert_eq!(
+            parse_error(Day01, "3   4\n4 3\n"),
+            "line 2, column 1: expected \"   \", found \"4 3\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day01);
+}
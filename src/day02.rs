@@ -1,14 +1,10 @@
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/02/input.txt");
+use crate::{parse, ParseError, Solution};
 
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     input
         .trim()
         .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|val| val.parse::<i32>().expect("a number"))
-                .collect::<Vec<_>>()
-        })
+        .map(|line| line.split_whitespace().map(parse::number).collect())
         .collect()
 }
 
@@ -28,17 +24,17 @@ fn is_safe_decreasing(values: &[i32]) -> bool {
         .all(|diff| (1..=3).contains(&diff))
 }
 
-fn p1(input: &str) -> String {
-    parse_input(input)
-        .into_iter()
+fn p1(reports: &[Vec<i32>]) -> String {
+    reports
+        .iter()
         .filter(|values| is_safe_increasing(values) || is_safe_decreasing(values))
         .count()
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    parse_input(input)
-        .into_iter()
+fn p2(reports: &[Vec<i32>]) -> String {
+    reports
+        .iter()
         .filter(|values| {
             is_safe_increasing(values)
                 || is_safe_decreasing(values)
@@ -56,14 +52,32 @@ fn p2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"7 6 4 2 1
 1 2 7 8 9
@@ -74,21 +88,21 @@ mod tests {
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "2");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "421");
+        assert_eq!(part1(Day02, SAMPLE_INPUT), "2");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "4");
+        assert_eq!(part2(Day02, SAMPLE_INPUT), "4");
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "476");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day02, "7 6 4\n1 2 x\n"),
+            "line 2, column 5: expected a number, found \"x\""
+        );
     }
+
+    crate::answers::actual_tests!(Day02);
 }
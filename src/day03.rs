@@ -0,0 +1,115 @@
+use regex::{Captures, Regex};
+
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Statement {
+    Multiply(i64, i64),
+    Do,
+    Dont,
+}
+
+impl Statement {
+    fn parse(captures: Captures) -> Result<Self, ParseError> {
+        let text = &captures[0];
+
+        Ok(match text {
+            "do()" => Statement::Do,
+            "don't()" => Statement::Dont,
+            _ => {
+                let a = parse::number(captures.get(1).expect("group 1 in mul").as_str())?;
+                let b = parse::number(captures.get(2).expect("group 2 in mul").as_str())?;
+                Statement::Multiply(a, b)
+            }
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Statement>, ParseError> {
+    Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)")
+        .expect("valid regex")
+        .captures_iter(input)
+        .map(Statement::parse)
+        .collect()
+}
+
+fn p1(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Multiply(a, b) => a * b,
+            Statement::Do | Statement::Dont => 0,
+        })
+        .sum::<i64>()
+        .to_string()
+}
+
+fn p2(statements: &[Statement]) -> String {
+    statements
+        .iter()
+        .fold((0i64, true), |(acc, enabled), statement| match statement {
+            Statement::Do => (acc, true),
+            Statement::Dont => (acc, false),
+            Statement::Multiply(a, b) => {
+                if enabled {
+                    (acc + a * b, enabled)
+                } else {
+                    (acc, enabled)
+                }
+            }
+        })
+        .0
+        .to_string()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Statement>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{part1, part2};
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(
+            part1(
+                Day03,
+                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+            ),
+            "161"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            part2(
+                Day03,
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+            ),
+            "48"
+        );
+    }
+
+    crate::answers::actual_tests!(Day03);
+}
@@ -0,0 +1,100 @@
+use glam::IVec2;
+
+use crate::{
+    grid::{Grid, DELTAS_8},
+    ParseError, Solution,
+};
+
+fn p1(grid: &Grid<char>) -> String {
+    grid.positions()
+        .map(|pos| {
+            DELTAS_8
+                .into_iter()
+                .filter(|delta| {
+                    "XMAS"
+                        .chars()
+                        .enumerate()
+                        .all(|(step, ch)| grid.get(pos + delta * step as i32) == Some(&ch))
+                })
+                .count()
+        })
+        .sum::<usize>()
+        .to_string()
+}
+
+fn p2(grid: &Grid<char>) -> String {
+    grid.positions()
+        .filter(|pos| {
+            if grid[*pos] == 'A' {
+                let ring = [
+                    IVec2::new(-1, -1),
+                    IVec2::new(1, -1),
+                    IVec2::new(1, 1),
+                    IVec2::new(-1, 1),
+                ]
+                .into_iter()
+                .map(|delta| grid.get(*pos + delta).copied().unwrap_or(' '))
+                .collect::<String>();
+                ["MMSS", "SMMS", "SSMM", "MSSM"]
+                    .into_iter()
+                    .any(|candidate| ring == candidate)
+            } else {
+                false
+            }
+        })
+        .count()
+        .to_string()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{part1, part2};
+
+    const SAMPLE_INPUT: &str = r"
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+    ";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day04, SAMPLE_INPUT), "18");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day04, SAMPLE_INPUT), "9");
+    }
+
+    crate::answers::actual_tests!(Day04);
+}
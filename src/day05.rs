@@ -1,11 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::{parse, ParseError, Solution};
+
 // input commentary: actual input's rulebook have cyclic paths, so using topo sort on the rulebook
 // would not work for both part 1 and 2. However, the updates themselves are specially crafted to
 // not cause a cyclic loop, so it might work on the updates?? never tried though
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/05/input.txt");
-
-struct Graph {
+pub struct Graph {
     children: HashMap<usize, HashSet<usize>>,
 }
 
@@ -21,23 +21,19 @@ impl Graph {
     }
 }
 
-fn parse_input(input: &str) -> (Graph, Vec<Vec<usize>>) {
-    let (rules, updates) = input
-        .trim()
-        .split_once("\n\n")
-        .expect("input to have two sections as described in problem");
+fn parse_input(input: &str) -> Result<(Graph, Vec<Vec<usize>>), ParseError> {
+    let (rules, updates) = parse::split_sections(input.trim())?;
 
-    (
+    Ok((
         rules
             .trim()
             .lines()
-            .map(|line| line.split_once("|").expect("input to be of format a|b"))
-            .map(|(parent, child)| {
-                (
-                    parent.parse().expect("positive integer"),
-                    child.parse().expect("positive integer"),
-                )
+            .map(|line| {
+                let (parent, child) = parse::split_once(line, "|")?;
+                Ok((parse::number(parent)?, parse::number(child)?))
             })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
             .fold(Graph::new(), |mut graph, (parent, child)| {
                 graph.connect(parent, child);
                 graph
@@ -45,14 +41,9 @@ fn parse_input(input: &str) -> (Graph, Vec<Vec<usize>>) {
         updates
             .trim()
             .lines()
-            .map(|update| {
-                update
-                    .split(",")
-                    .map(|page| page.parse().expect("positive integer"))
-                    .collect()
-            })
-            .collect(),
-    )
+            .map(|update| parse::numbers(update, ","))
+            .collect::<Result<_, _>>()?,
+    ))
 }
 
 fn is_valid_ordering(rules: &Graph, update: &[usize]) -> bool {
@@ -74,13 +65,11 @@ fn get_middle_page(update: &[usize]) -> usize {
     *update.get(update.len() / 2).unwrap()
 }
 
-fn p1(input: &str) -> String {
-    let (rules, updates) = parse_input(input);
-
+fn p1((rules, updates): &(Graph, Vec<Vec<usize>>)) -> String {
     updates
-        .into_iter()
-        .filter(|update| is_valid_ordering(&rules, update))
-        .map(|update| get_middle_page(&update))
+        .iter()
+        .filter(|update| is_valid_ordering(rules, update))
+        .map(|update| get_middle_page(update))
         .sum::<usize>()
         .to_string()
 }
@@ -114,26 +103,42 @@ fn fix_page_ordering(rules: &Graph, update: &[usize]) -> Vec<usize> {
     result
 }
 
-fn p2(input: &str) -> String {
-    let (rules, updates) = parse_input(input);
-
+fn p2((rules, updates): &(Graph, Vec<Vec<usize>>)) -> String {
     updates
-        .into_iter()
-        .filter(|update| !is_valid_ordering(&rules, update))
-        .map(|update| fix_page_ordering(&rules, &update))
+        .iter()
+        .filter(|update| !is_valid_ordering(rules, update))
+        .map(|update| fix_page_ordering(rules, update))
         .map(|update| get_middle_page(&update))
         .sum::<usize>()
         .to_string()
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Graph, Vec<Vec<usize>>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"47|53
 97|13
@@ -167,21 +172,25 @@ mod tests {
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "143");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "4924");
+        assert_eq!(part1(Day05, SAMPLE_INPUT), "143");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "123");
+        assert_eq!(part2(Day05, SAMPLE_INPUT), "123");
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "6085");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day05, "47|53\n97|x\n\n75,47\n"),
+            "line 2, column 4: expected a number, found \"x\""
+        );
+        assert_eq!(
+            parse_error(Day05, "47|53\n75,47\n"),
+            "line 2, column 6: expected a blank line between sections"
+        );
     }
+
+    crate::answers::actual_tests!(Day05);
 }
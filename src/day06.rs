@@ -0,0 +1,469 @@
+use ahash::{AHashMap, AHashSet};
+use glam::IVec2;
+
+use crate::{direction::Direction, grid::Grid, parse, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: IVec2,
+    pub dir: Direction,
+}
+
+impl Guard {
+    fn advance(self, map: &Map, extra: Option<IVec2>) -> Option<Self> {
+        let front_pos = self.pos + self.dir.delta();
+
+        // out of bounds in front ends the walk
+        if *map.obstacles.get(front_pos)? || Some(front_pos) == extra {
+            Some(Self {
+                pos: self.pos,
+                dir: self.dir.turn_right(),
+            })
+        } else {
+            Some(Self {
+                pos: front_pos,
+                dir: self.dir,
+            })
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    guard_start: Guard,
+    obstacles: Grid<bool>,
+}
+
+/// The loop a guard ends up walking forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index in [`Trace::steps`] of the first state of the loop.
+    pub entry: usize,
+    /// The number of states in the loop.
+    pub period: usize,
+    /// The obstacles the guard turns at, in the order it bumps into them from the entry.
+    pub turns: Vec<IVec2>,
+}
+
+/// The walk of a guard, one state per step, where turning in place is a step of its own.
+#[derive(Debug, Clone)]
+pub struct Trace {
+    /// The obstacle added to the map, if any.
+    pub extra: Option<IVec2>,
+    /// Every state in order from the start, until the guard leaves the map or is about to walk the
+    /// loop a second time.
+    pub steps: Vec<Guard>,
+    pub cycle: Option<Cycle>,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |ch| matches!(ch, '.' | '#' | '^').then_some(ch))?;
+
+        Ok(Self {
+            guard_start: Guard {
+                pos: grid.find(&'^').ok_or_else(|| {
+                    ParseError::new(parse::end_of(input), "expected a guard `^` in the map")
+                })?,
+                dir: Direction::Up,
+            },
+            obstacles: grid.map(|ch| *ch == '#'),
+        })
+    }
+
+    /// Walks the guard from the start, with an obstacle added at `extra` if given.
+    pub fn trace(&self, extra: Option<IVec2>) -> Trace {
+        let mut seen = AHashMap::new();
+        let mut steps = vec![];
+        let mut next = Some(self.guard_start);
+
+        while let Some(guard) = next {
+            if let Some(&entry) = seen.get(&guard) {
+                let turns = (entry..steps.len())
+                    .filter(|&i| {
+                        let after: &Guard = steps.get(i + 1).unwrap_or(&steps[entry]);
+                        after.dir != steps[i].dir
+                    })
+                    .map(|i| steps[i].pos + steps[i].dir.delta())
+                    .collect();
+                let cycle = Cycle {
+                    entry,
+                    period: steps.len() - entry,
+                    turns,
+                };
+                return Trace {
+                    extra,
+                    steps,
+                    cycle: Some(cycle),
+                };
+            }
+
+            seen.insert(guard, steps.len());
+            steps.push(guard);
+            next = guard.advance(self, extra);
+        }
+
+        Trace {
+            extra,
+            steps,
+            cycle: None,
+        }
+    }
+
+    /// The map with the path of `trace` drawn over it like in the puzzle: `|` and `-` where the
+    /// guard only walks up and down or left and right, `+` where it does both, and `O` for the
+    /// added obstacle.
+    pub fn render(&self, trace: &Trace) -> String {
+        const VERTICAL: u8 = 1;
+        const HORIZONTAL: u8 = 2;
+
+        let mut walked = self.obstacles.map(|_| 0);
+        for guard in &trace.steps {
+            walked[guard.pos] |= match guard.dir {
+                Direction::Up | Direction::Down => VERTICAL,
+                Direction::Left | Direction::Right => HORIZONTAL,
+            };
+        }
+
+        Grid::from_fn(self.obstacles.width(), self.obstacles.height(), |pos| {
+            if self.obstacles[pos] {
+                '#'
+            } else if Some(pos) == trace.extra {
+                'O'
+            } else if pos == self.guard_start.pos {
+                '^'
+            } else {
+                match walked[pos] {
+                    VERTICAL => '|',
+                    HORIZONTAL => '-',
+                    0 => '.',
+                    _ => '+',
+                }
+            }
+        })
+        .to_string()
+    }
+}
+
+fn get_guard_p1_path(map: &Map) -> AHashSet<IVec2> {
+    map.trace(None)
+        .steps
+        .into_iter()
+        .map(|guard| guard.pos)
+        .collect()
+}
+
+fn p1(map: &Map) -> String {
+    get_guard_p1_path(map).len().to_string()
+}
+
+/// For every cell and direction, where the guard next turns: the cell in front of the obstacle
+/// it bumps into, facing its new direction. `None` if it walks off the map instead.
+struct JumpTable {
+    width: usize,
+    next: Vec<Option<Guard>>,
+}
+
+impl JumpTable {
+    fn new(obstacles: &Grid<bool>) -> Self {
+        let mut table = Self {
+            width: obstacles.width(),
+            next: vec![None; obstacles.width() * obstacles.height() * 4],
+        };
+
+        for dir in Direction::ALL {
+            // the cell in front of each cell is visited first, so its jump can be reused
+            let order = |len: usize, step: i32| -> Vec<i32> {
+                if step > 0 {
+                    (0..len as i32).rev().collect()
+                } else {
+                    (0..len as i32).collect()
+                }
+            };
+            let (xs, ys) = (
+                order(obstacles.width(), dir.delta().x),
+                order(obstacles.height(), dir.delta().y),
+            );
+
+            for pos in ys
+                .iter()
+                .flat_map(|&y| xs.iter().map(move |&x| IVec2::new(x, y)))
+            {
+                let front = pos + dir.delta();
+                let idx = table.index(Guard { pos, dir });
+                table.next[idx] = match obstacles.get(front) {
+                    None => None,
+                    Some(true) => Some(Guard {
+                        pos,
+                        dir: dir.turn_right(),
+                    }),
+                    Some(false) => table.next[table.index(Guard { pos: front, dir })],
+                };
+            }
+        }
+
+        table
+    }
+
+    fn index(&self, guard: Guard) -> usize {
+        (guard.pos.y as usize * self.width + guard.pos.x as usize) * 4 + guard.dir as usize
+    }
+
+    /// The next turn of `guard` with an extra obstacle at `extra`, which can only cut the jump
+    /// short if it is on the row or column ahead of the guard.
+    fn jump(&self, guard: Guard, extra: IVec2) -> Option<Guard> {
+        let next = self.next[self.index(guard)];
+
+        let offset = extra - guard.pos;
+        let distance = offset.dot(guard.dir.delta());
+        let ahead = distance > 0 && offset == guard.dir.delta() * distance;
+        let before_next =
+            next.is_none_or(|next| distance <= (next.pos - guard.pos).dot(guard.dir.delta()));
+
+        if ahead && before_next {
+            Some(Guard {
+                pos: extra - guard.dir.delta(),
+                dir: guard.dir.turn_right(),
+            })
+        } else {
+            next
+        }
+    }
+
+    /// Whether the guard walks in a loop once an obstacle is added at `extra`. `visited` is only
+    /// passed in to reuse its allocation.
+    fn loops(&self, start: Guard, extra: IVec2, visited: &mut AHashSet<Guard>) -> bool {
+        visited.clear();
+        let mut guard = start;
+
+        while let Some(next) = self.jump(guard, extra) {
+            if !visited.insert(next) {
+                return true;
+            }
+            guard = next;
+        }
+
+        false
+    }
+}
+
+fn p2(map: &Map) -> String {
+    let table = JumpTable::new(&map.obstacles);
+    let candidates = get_guard_p1_path(map)
+        .into_iter()
+        .filter(|pos| *pos != map.guard_start.pos)
+        .collect::<Vec<_>>();
+
+    let workers = std::thread::available_parallelism().map_or(1, |workers| workers.get());
+    let chunk_size = candidates.len().div_ceil(workers).max(1);
+
+    std::thread::scope(|scope| {
+        candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(|| {
+                    let mut visited = AHashSet::new();
+                    chunk
+                        .iter()
+                        .filter(|pos| table.loops(map.guard_start, **pos, &mut visited))
+                        .count()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|worker| worker.join().expect("worker panicked"))
+            .sum::<usize>()
+    })
+    .to_string()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Map;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rng::XorShift,
+        solution::testing::{parse_error, part1, part2},
+    };
+
+    const SAMPLE_INPUT: &str = r"
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day06, SAMPLE_INPUT), "41");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day06, SAMPLE_INPUT), "6");
+    }
+
+    #[test]
+    fn test_jump_table() {
+        let map = Map::parse_input(SAMPLE_INPUT).unwrap();
+        let table = JumpTable::new(&map.obstacles);
+        let start = map.guard_start;
+        let far = IVec2::new(-1, -1);
+
+        let first = table.jump(start, far).unwrap();
+        assert_eq!(first.pos, IVec2::new(4, 1));
+        assert_eq!(first.dir, Direction::Right);
+        assert_eq!(
+            table.jump(start, IVec2::new(4, 3)).map(|guard| guard.pos),
+            Some(IVec2::new(4, 4))
+        );
+        // behind the guard, or beyond the obstacle it bumps into
+        assert_eq!(table.jump(start, IVec2::new(4, 8)), Some(first));
+        assert_eq!(table.jump(start, IVec2::new(4, 0)), Some(first));
+
+        let mut visited = AHashSet::new();
+        assert!(table.loops(start, IVec2::new(3, 6), &mut visited));
+        assert!(!table.loops(start, IVec2::new(5, 5), &mut visited));
+    }
+
+    /// A random map of `size` with about one obstacle in ten cells, and the guard somewhere.
+    fn random_map(size: usize, seed: u64) -> String {
+        let mut rng = XorShift::new(seed);
+        let mut cells = (0..size * size)
+            .map(|_| if rng.below(10) == 0 { '#' } else { '.' })
+            .collect::<Vec<_>>();
+        cells[rng.below(size * size)] = '^';
+        cells
+            .chunks(size)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn test_jump_table_matches_trace() {
+        let random = (1..=3).map(|seed| random_map(30, seed));
+        for input in [SAMPLE_INPUT.to_string()].into_iter().chain(random) {
+            let map = Map::parse_input(&input).unwrap();
+            let table = JumpTable::new(&map.obstacles);
+            let mut visited = AHashSet::new();
+            let mut loops = 0;
+
+            // every free cell, not only the ones on the path of the guard
+            for pos in map.obstacles.positions() {
+                if map.obstacles[pos] || pos == map.guard_start.pos {
+                    continue;
+                }
+                let expected = map.trace(Some(pos)).cycle.is_some();
+                assert_eq!(
+                    table.loops(map.guard_start, pos, &mut visited),
+                    expected,
+                    "obstacle at {}",
+                    pos
+                );
+                loops += expected as usize;
+            }
+            assert!(loops > 0);
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let map = Map::parse_input(SAMPLE_INPUT).unwrap();
+
+        let walk = map.trace(None);
+        assert_eq!(walk.cycle, None);
+        assert_eq!(walk.steps[0], map.guard_start);
+        assert_eq!(walk.steps.last().unwrap().pos, IVec2::new(7, 9));
+
+        // the guard is back at the start facing up after a single round
+        let cycle = map.trace(Some(IVec2::new(3, 6))).cycle.unwrap();
+        assert_eq!(cycle.entry, 0);
+        assert_eq!(cycle.period, 22);
+        assert_eq!(
+            cycle.turns,
+            [
+                IVec2::new(4, 0),
+                IVec2::new(9, 1),
+                IVec2::new(8, 7),
+                IVec2::new(3, 6)
+            ]
+        );
+
+        let trace = map.trace(Some(IVec2::new(6, 7)));
+        let cycle = trace.cycle.clone().unwrap();
+        assert_eq!(cycle.entry, 19);
+        assert_eq!(cycle.period, 16);
+        assert_eq!(trace.steps.len(), 35);
+        assert_eq!(
+            trace.steps[cycle.entry],
+            Guard {
+                pos: IVec2::new(6, 6),
+                dir: Direction::Left
+            }
+        );
+        assert_eq!(
+            cycle.turns,
+            [
+                IVec2::new(1, 6),
+                IVec2::new(2, 3),
+                IVec2::new(7, 4),
+                IVec2::new(6, 7)
+            ]
+        );
+        assert_eq!(
+            map.render(&trace),
+            "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+-^-+-+.
+......O.#.
+#.........
+......#..."
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day06, "....\n.#..\n"),
+            "line 3, column 1: expected a guard `^` in the map"
+        );
+        assert_eq!(
+            parse_error(Day06, "..^.\n.x..\n"),
+            "line 2, column 2: unexpected character, found \"x\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day06);
+}
@@ -0,0 +1,145 @@
+use crate::{digits, parse, ParseError, Solution};
+
+fn equation_solvable(coefficients: &[u64], expected: u64, can_concat: bool) -> bool {
+    fn compute(
+        coefficients: &[u64],
+        expected: u64,
+        can_concat: bool,
+        index: usize,
+        acc: u64,
+    ) -> bool {
+        if index >= coefficients.len() {
+            acc == expected
+        } else if acc > expected {
+            // only compare acc "greater than" expected, not "equal", because if
+            // the remaining numbers are 0 and/or 1, then can still + or *
+            // to get the answer
+            false
+        } else if compute(
+            coefficients,
+            expected,
+            can_concat,
+            index + 1,
+            acc + coefficients[index],
+        ) || compute(
+            coefficients,
+            expected,
+            can_concat,
+            index + 1,
+            acc * coefficients[index],
+        ) {
+            true
+        } else if !can_concat {
+            false
+        } else {
+            match digits::checked_concat(acc, coefficients[index]) {
+                Some(concatenated) if concatenated <= expected => {
+                    compute(coefficients, expected, can_concat, index + 1, concatenated)
+                }
+                _ => false,
+            }
+        }
+    }
+    if coefficients.is_empty() {
+        expected == 0
+    } else {
+        compute(coefficients, expected, can_concat, 0, 0)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (test_value, coefficients) = parse::split_once(line.trim(), ": ")?;
+            Ok((
+                parse::number(test_value)?,
+                parse::numbers(coefficients, " ")?,
+            ))
+        })
+        .collect()
+}
+
+fn solve(equations: &[(u64, Vec<u64>)], can_concat: bool) -> String {
+    equations
+        .iter()
+        .filter(|(test_value, coefficients)| {
+            equation_solvable(coefficients, *test_value, can_concat)
+        })
+        .map(|(test_value, _)| test_value)
+        .sum::<u64>()
+        .to_string()
+}
+
+fn p1(equations: &[(u64, Vec<u64>)]) -> String {
+    solve(equations, false)
+}
+
+fn p2(equations: &[(u64, Vec<u64>)]) -> String {
+    solve(equations, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<(u64, Vec<u64>)>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT: &str = r"
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day07, SAMPLE_INPUT), "3749");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day07, SAMPLE_INPUT), "11387");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day07, "190: 10 19\n3267: 81 4O 27\n"),
+            "line 2, column 10: expected a number, found \"4O\""
+        );
+        assert_eq!(
+            parse_error(Day07, "190 10 19\n"),
+            "line 1, column 1: expected \": \", found \"190 10 19\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day07);
+}
@@ -2,52 +2,51 @@ use ahash::{HashMap, HashMapExt, HashSet};
 use glam::IVec2;
 use itertools::Itertools;
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/08/input.txt");
+use crate::{parse, ParseError, Solution};
 
 fn in_bounds(coord: &IVec2, bounds: &IVec2) -> bool {
     coord.x >= 0 && coord.x < bounds.x && coord.y >= 0 && coord.y < bounds.y
 }
 
-struct Map {
+pub struct Map {
     antennas: HashMap<char, Vec<IVec2>>,
     bounds: IVec2,
 }
 
 impl Map {
-    fn parse_input(input: &str) -> Self {
-        Self {
-            antennas: input.trim().lines().enumerate().fold(
-                HashMap::new(),
-                |mut acc, (y, line)| {
-                    let y = y as i32;
-
-                    line.trim().chars().enumerate().for_each(|(x, ch)| {
-                        if ch != '.' {
-                            let x = x as i32;
-                            acc.entry(ch).or_default().push(IVec2::new(x, y));
-                        }
-                    });
-
-                    acc
-                },
-            ),
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut antennas = HashMap::<char, Vec<IVec2>>::new();
+
+        for (y, line) in input.trim().lines().enumerate() {
+            for (x, ch, text) in parse::row_chars(line.trim()) {
+                if ch.is_ascii_alphanumeric() {
+                    antennas
+                        .entry(ch)
+                        .or_default()
+                        .push(IVec2::new(x as i32, y as i32));
+                } else if ch != '.' {
+                    return Err(parse::unexpected_char(text));
+                }
+            }
+        }
+
+        let first_line = input
+            .trim()
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(input, "expected a map, but the input is empty"))?;
+
+        Ok(Self {
+            antennas,
             bounds: IVec2::new(
-                input
-                    .trim()
-                    .lines()
-                    .nth(0)
-                    .expect("non-empty input")
-                    .chars()
-                    .count() as i32,
+                first_line.trim().chars().count() as i32,
                 input.trim().lines().count() as i32,
             ),
-        }
+        })
     }
 }
 
-fn p1(input: &str) -> String {
-    let map = Map::parse_input(input);
-
+fn p1(map: &Map) -> String {
     map.antennas
         .values()
         .flat_map(|pos| {
@@ -66,9 +65,7 @@ fn p1(input: &str) -> String {
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    let map = Map::parse_input(input);
-
+fn p2(map: &Map) -> String {
     map.antennas
         .values()
         .flat_map(|pos| {
@@ -96,14 +93,32 @@ fn p2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Map;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"
 ............
@@ -122,21 +137,25 @@ mod tests {
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "14");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "336");
+        assert_eq!(part1(Day08, SAMPLE_INPUT), "14");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "34");
+        assert_eq!(part2(Day08, SAMPLE_INPUT), "34");
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "1131");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day08, "..a.\n.#..\n"),
+            "line 2, column 2: unexpected character, found \"#\""
+        );
+        assert_eq!(
+            parse_error(Day08, "\n"),
+            "line 1, column 1: expected a map, but the input is empty, found \"\\n\""
+        );
     }
+
+    crate::answers::actual_tests!(Day08);
 }
@@ -0,0 +1,433 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    fmt::Display,
+};
+
+use crate::{parse, ParseError, Solution};
+
+/// A run of blocks of the file `id`. A file is a single extent, unless block-level compaction
+/// splits it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extent {
+    pub id: usize,
+    pub pos: usize,
+    pub len: usize,
+}
+
+impl Extent {
+    fn end(&self) -> usize {
+        self.pos + self.len
+    }
+
+    fn checksum(&self) -> usize {
+        self.id * (self.pos * self.len + self.len * self.len.saturating_sub(1) / 2)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Moves blocks one at a time from the end of the disk to the leftmost free block.
+    Block,
+    /// Moves whole files, from the highest id down, to the leftmost free span they fit in.
+    WholeFile,
+    /// Slides every file to the left in its current order, leaving all the free space at the end.
+    DefragmentToEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    /// Ordered by position.
+    extents: Vec<Extent>,
+    size: usize,
+}
+
+impl DiskMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut extents = vec![];
+        let mut size = 0;
+
+        for (idx, ch, text) in parse::row_chars(input.trim()) {
+            let len = ch
+                .to_digit(10)
+                .ok_or_else(|| ParseError::new(text, "expected a digit"))?
+                as usize;
+            if idx % 2 == 0 {
+                extents.push(Extent {
+                    id: idx / 2,
+                    pos: size,
+                    len,
+                });
+            }
+            size += len;
+        }
+
+        Ok(Self { extents, size })
+    }
+
+    pub fn extents(&self) -> &[Extent] {
+        &self.extents
+    }
+
+    /// The number of blocks of the disk, free or not.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The free spans as `(pos, len)`, including empty ones between adjacent extents.
+    fn gaps(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let ends = std::iter::once(0).chain(self.extents.iter().map(Extent::end));
+        let starts = self
+            .extents
+            .iter()
+            .map(|extent| extent.pos)
+            .chain(std::iter::once(self.size));
+        ends.zip(starts).map(|(end, start)| (end, start - end))
+    }
+
+    pub fn compact(&self, strategy: Strategy) -> Self {
+        let extents = match strategy {
+            Strategy::Block => self.compact_blocks(),
+            Strategy::WholeFile => self.compact_files(),
+            Strategy::DefragmentToEnd => self.defragment(),
+        };
+
+        Self {
+            extents,
+            size: self.size,
+        }
+    }
+
+    /// Fills the gap before each extent from the last ones, moving whole runs of blocks at once.
+    fn compact_blocks(&self) -> Vec<Extent> {
+        let mut extents = self
+            .extents
+            .iter()
+            .filter(|extent| extent.len > 0)
+            .copied()
+            .collect::<VecDeque<_>>();
+        let mut compacted = vec![];
+        let mut pos = 0;
+
+        while let Some(first) = extents.pop_front() {
+            while pos < first.pos {
+                let Some(mut last) = extents.pop_back() else {
+                    // `first` is the last extent, its blocks from the end fill the gap before it
+                    break;
+                };
+                let len = last.len.min(first.pos - pos);
+                push_merged(&mut compacted, Extent { pos, len, ..last });
+                pos += len;
+                last.len -= len;
+                if last.len > 0 {
+                    extents.push_back(last);
+                }
+            }
+            push_merged(&mut compacted, Extent { pos, ..first });
+            pos += first.len;
+        }
+
+        compacted
+    }
+
+    /// Free spans are kept in a min-heap of start positions per length, so the leftmost span an
+    /// extent fits in is the smallest top among the heaps of its length and above. Only the spans
+    /// left of an extent are considered and the space it frees is not reused, which is exact when
+    /// the files are in order of id, as they are in a parsed disk map.
+    fn compact_files(&self) -> Vec<Extent> {
+        let max_len = self.gaps().map(|(_, len)| len).max().unwrap_or(0);
+        let mut free = vec![BinaryHeap::new(); max_len + 1];
+        for (pos, len) in self.gaps().filter(|(_, len)| *len > 0) {
+            free[len].push(Reverse(pos));
+        }
+
+        let mut order = (0..self.extents.len()).collect::<Vec<_>>();
+        order.sort_by_key(|idx| Reverse(self.extents[*idx].id));
+
+        let mut extents = self.extents.clone();
+        for idx in order {
+            let Extent { pos, len, .. } = extents[idx];
+            let span = (len.max(1)..free.len())
+                .filter_map(|span_len| {
+                    free[span_len]
+                        .peek()
+                        .map(|Reverse(start)| (*start, span_len))
+                })
+                .filter(|(start, _)| *start < pos)
+                .min();
+
+            if let Some((start, span_len)) = span {
+                free[span_len].pop();
+                if span_len > len {
+                    free[span_len - len].push(Reverse(start + len));
+                }
+                extents[idx].pos = start;
+            }
+        }
+
+        extents.sort_by_key(|extent| extent.pos);
+        extents
+    }
+
+    fn defragment(&self) -> Vec<Extent> {
+        let mut defragmented = vec![];
+        let mut pos = 0;
+
+        for extent in &self.extents {
+            push_merged(&mut defragmented, Extent { pos, ..*extent });
+            pos += extent.len;
+        }
+
+        defragmented
+    }
+
+    pub fn checksum(&self) -> usize {
+        self.extents.iter().map(Extent::checksum).sum()
+    }
+
+    /// The disk map the puzzle input is made of, or `None` if it cannot be written as one: the ids
+    /// are implied by the order of the files, so it must start with file 0 followed by the next ids
+    /// in order, and every file and free span must fit in a single digit.
+    pub fn to_dense(&self) -> Option<String> {
+        if self.extents.first().is_some_and(|first| first.pos != 0) {
+            return None;
+        }
+        let digit = |len: usize| char::from_digit(u32::try_from(len).ok()?, 10);
+
+        let mut dense = String::new();
+        for ((id, extent), (_, gap)) in self.extents.iter().enumerate().zip(self.gaps().skip(1)) {
+            if extent.id != id {
+                return None;
+            }
+            dense.push(digit(extent.len)?);
+            // a last empty span is left out, like the puzzle input does
+            if gap > 0 || id + 1 < self.extents.len() {
+                dense.push(digit(gap)?);
+            }
+        }
+
+        Some(dense)
+    }
+}
+
+/// Pushes `extent`, extending the last one instead if it is the same file right before it.
+fn push_merged(extents: &mut Vec<Extent>, extent: Extent) {
+    match extents.last_mut() {
+        Some(last) if last.id == extent.id && last.end() == extent.pos => last.len += extent.len,
+        _ => extents.push(extent),
+    }
+}
+
+/// The blocks in the layout of the puzzle text, like `00...111...2`, with a `.` for free blocks.
+impl Display for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (extent, (_, gap)) in self.extents.iter().zip(self.gaps()) {
+            write!(f, "{}", ".".repeat(gap))?;
+            write!(f, "{}", extent.id.to_string().repeat(extent.len))?;
+        }
+        let (_, gap) = self.gaps().last().expect("one more gap than extents");
+        write!(f, "{}", ".".repeat(gap))
+    }
+}
+
+fn p1(disk_map: &DiskMap) -> String {
+    disk_map.compact(Strategy::Block).checksum().to_string()
+}
+
+fn p2(disk_map: &DiskMap) -> String {
+    disk_map.compact(Strategy::WholeFile).checksum().to_string()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = DiskMap;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        DiskMap::parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT: &str = r"2333133121414131402";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day09, SAMPLE_INPUT), "1928");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day09, SAMPLE_INPUT), "2858");
+    }
+
+    #[test]
+    fn test_display() {
+        let disk_map = Day09.parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(
+            disk_map.to_string(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        assert_eq!(
+            disk_map.compact(Strategy::Block).to_string(),
+            "0099811188827773336446555566.............."
+        );
+        assert_eq!(
+            disk_map.compact(Strategy::WholeFile).to_string(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(
+            disk_map.compact(Strategy::DefragmentToEnd).to_string(),
+            "0011123334455556666777888899.............."
+        );
+        assert_eq!(Day09.parse("12").unwrap().to_string(), "0..");
+    }
+
+    #[test]
+    fn test_to_dense() {
+        for dense in [SAMPLE_INPUT, "12345", "10101", "0", "1902"] {
+            assert_eq!(
+                Day09.parse(dense).unwrap().to_dense().as_deref(),
+                Some(dense)
+            );
+        }
+
+        let disk_map = Day09.parse(SAMPLE_INPUT).unwrap();
+        // file 9 is moved before file 1, and the free space at the end is too long for a digit
+        assert_eq!(disk_map.compact(Strategy::WholeFile).to_dense(), None);
+        assert_eq!(disk_map.compact(Strategy::DefragmentToEnd).to_dense(), None);
+        assert_eq!(
+            Day09
+                .parse("2333")
+                .unwrap()
+                .compact(Strategy::DefragmentToEnd)
+                .to_dense()
+                .as_deref(),
+            Some("2036")
+        );
+    }
+
+    /// A random disk map of `len` digits, with files and free spans of 1 to 9 blocks.
+    fn random_disk_map(len: usize, seed: u64) -> String {
+        let mut rng = XorShift::new(seed);
+        (0..len)
+            .map(|_| char::from_digit(rng.below(9) as u32 + 1, 10).unwrap())
+            .collect()
+    }
+
+    /// Compacts the disk block by block, scanning for free space from the left for every move.
+    fn naive_checksum(disk_map: &str, whole_files: bool) -> usize {
+        let lens = disk_map
+            .chars()
+            .map(|ch| ch.to_digit(10).unwrap() as usize)
+            .collect::<Vec<_>>();
+        let mut blocks = lens
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, len)| std::iter::repeat_n((idx % 2 == 0).then_some(idx / 2), *len))
+            .collect::<Vec<_>>();
+
+        if whole_files {
+            for fid in (0..lens.len().div_ceil(2)).rev() {
+                let start = blocks.iter().position(|block| *block == Some(fid)).unwrap();
+                let len = lens[fid * 2];
+                let target =
+                    (0..start).find(|&pos| blocks[pos..pos + len].iter().all(Option::is_none));
+                if let Some(target) = target {
+                    blocks[target..target + len].fill(Some(fid));
+                    blocks[start..start + len].fill(None);
+                }
+            }
+        } else {
+            while let Some(free) = blocks.iter().position(Option::is_none) {
+                let last = blocks.iter().rposition(Option::is_some).unwrap();
+                if last < free {
+                    break;
+                }
+                blocks.swap(free, last);
+            }
+        }
+
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(pos, block)| block.map_or(0, |fid| pos * fid))
+            .sum()
+    }
+
+    #[test]
+    fn test_random() {
+        for seed in 1..=5 {
+            let input = random_disk_map(1001, seed);
+            let disk_map = Day09.parse(&input).unwrap();
+            assert_eq!(p1(&disk_map), naive_checksum(&input, false).to_string());
+            assert_eq!(p2(&disk_map), naive_checksum(&input, true).to_string());
+
+            let defragmented = disk_map.compact(Strategy::DefragmentToEnd);
+            assert_eq!(defragmented.gaps().filter(|(_, len)| *len > 0).count(), 1);
+            assert_eq!(defragmented.size(), disk_map.size());
+        }
+    }
+
+    #[test]
+    fn test_p2_stress() {
+        let disk_map = Day09.parse(&random_disk_map(200_001, 0x2024_1209)).unwrap();
+        let compacted = disk_map.compact(Strategy::WholeFile);
+
+        // every file is still there once, and no two overlap
+        let mut ids = compacted
+            .extents()
+            .iter()
+            .map(|extent| extent.id)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert!(ids.iter().copied().eq(0..100_001));
+        let blocks = |disk_map: &DiskMap| disk_map.extents().iter().map(|e| e.len).sum::<usize>();
+        assert_eq!(blocks(&compacted), blocks(&disk_map));
+        assert!(compacted
+            .extents()
+            .windows(2)
+            .all(|pair| pair[0].end() <= pair[1].pos));
+        assert!(compacted.checksum() < disk_map.checksum());
+    }
+
+    #[test]
+    #[ignore = "timing, run with --release --ignored"]
+    fn test_p2_stress_timing() {
+        let disk_map = Day09.parse(&random_disk_map(200_001, 0x2024_1209)).unwrap();
+        let start = std::time::Instant::now();
+        p2(&disk_map);
+        assert!(
+            start.elapsed().as_secs_f64() < 1.0,
+            "took {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day09, "2333x\n"),
+            "line 1, column 5: expected a digit, found \"x\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day09);
+}
@@ -0,0 +1,250 @@
+use ahash::{HashSet, HashSetExt};
+
+use glam::IVec2;
+
+use crate::{grid::Grid, ParseError, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Metric {
+    Score,
+    Rating,
+}
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |ch| {
+        if ch == '.' {
+            Some(999)
+        } else {
+            ch.to_digit(10)
+        }
+    })
+}
+
+fn solve(grid: &Grid<u32>, metric: Metric) -> String {
+    fn get_metric(grid: &Grid<u32>, pos: IVec2, metric: Metric) -> usize {
+        if grid[pos] != 0 {
+            0
+        } else {
+            let mut scores = HashSet::new();
+            let mut ratings = 0;
+
+            fn visit(
+                grid: &Grid<u32>,
+                pos: IVec2,
+                scores: &mut HashSet<IVec2>,
+                ratings: &mut usize,
+            ) {
+                let current_val = grid[pos];
+                let next_val = current_val + 1;
+
+                if current_val == 9 {
+                    scores.insert(pos);
+                    *ratings += 1;
+                } else {
+                    grid.neighbours_4(pos)
+                        .filter(|next_pos| grid[*next_pos] == next_val)
+                        .for_each(|next_pos| visit(grid, next_pos, scores, ratings));
+                }
+            }
+
+            visit(grid, pos, &mut scores, &mut ratings);
+
+            match metric {
+                Metric::Score => scores.len(),
+                Metric::Rating => ratings,
+            }
+        }
+    }
+
+    grid.positions()
+        .map(|pos| get_metric(grid, pos, metric))
+        .sum::<usize>()
+        .to_string()
+}
+
+fn p1(grid: &Grid<u32>) -> String {
+    solve(grid, Metric::Score)
+}
+
+fn p2(grid: &Grid<u32>) -> String {
+    solve(grid, Metric::Rating)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<u32>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(
+            part1(
+                Day10,
+                r"
+0123
+1234
+8765
+9876
+"
+            ),
+            "1"
+        );
+        assert_eq!(
+            part1(
+                Day10,
+                r"
+...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9
+"
+            ),
+            "2"
+        );
+        assert_eq!(
+            part1(
+                Day10,
+                r"
+..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....
+"
+            ),
+            "4"
+        );
+        assert_eq!(
+            part1(
+                Day10,
+                r"
+10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01
+"
+            ),
+            "3"
+        );
+        assert_eq!(
+            part1(
+                Day10,
+                r"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+"
+            ),
+            "36"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            part2(
+                Day10,
+                r"
+.....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9....
+"
+            ),
+            "3"
+        );
+        assert_eq!(
+            part2(
+                Day10,
+                r"
+..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....
+"
+            ),
+            "13"
+        );
+        assert_eq!(
+            part2(
+                Day10,
+                r"
+012345
+123456
+234567
+345678
+4.6789
+56789.
+"
+            ),
+            "227"
+        );
+        assert_eq!(
+            part2(
+                Day10,
+                r"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+"
+            ),
+            "81"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day10, "0123\n1x34\n"),
+            "line 2, column 2: unexpected character, found \"x\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day10);
+}
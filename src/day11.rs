@@ -0,0 +1,265 @@
+use std::fmt::Display;
+
+use ahash::{HashMap, HashMapExt};
+
+use crate::{digits, parse, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse::numbers(input.trim(), " ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// A stone engraved with `from` is replaced by one engraved with `to`.
+    Replace { from: u64, to: u64 },
+    /// A stone with an even number of digits is split into its left and right halves.
+    SplitEvenDigits,
+    /// Any stone is replaced by one engraved with its number times the factor.
+    Multiply(u64),
+}
+
+impl Rule {
+    /// The stones replacing `stone`, or `None` if the rule does not apply to it.
+    fn apply(self, stone: u64) -> Option<Vec<u64>> {
+        match self {
+            Rule::Replace { from, to } => (stone == from).then(|| vec![to]),
+            Rule::SplitEvenDigits => {
+                digits::split_half(stone).map(|(left, right)| vec![left, right])
+            }
+            Rule::Multiply(factor) => Some(vec![stone
+                .checked_mul(factor)
+                .expect("stone number overflows u64")]),
+        }
+    }
+}
+
+/// The rules of a blink, of which the first that applies changes a stone. A stone no rule applies
+/// to is left as it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules(pub Vec<Rule>);
+
+impl Default for Rules {
+    /// The rules of the puzzle.
+    fn default() -> Self {
+        Self(vec![
+            Rule::Replace { from: 0, to: 1 },
+            Rule::SplitEvenDigits,
+            Rule::Multiply(2024),
+        ])
+    }
+}
+
+/// How many stones there are of a number. Adding panics on overflow rather than wrapping.
+pub trait Count: Clone + Default + Display {
+    fn one() -> Self;
+    fn add(&mut self, other: &Self);
+}
+
+impl Count for u64 {
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self = self.checked_add(*other).expect("too many stones for u64");
+    }
+}
+
+impl Count for u128 {
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self = self.checked_add(*other).expect("too many stones for u128");
+    }
+}
+
+/// A count without bounds, which `u128` is not after some 200 blinks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BigCount {
+    /// Base 10^18 digits, least significant first.
+    limbs: Vec<u64>,
+}
+
+impl BigCount {
+    const BASE: u64 = 1_000_000_000_000_000_000;
+}
+
+impl Count for BigCount {
+    fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+
+    fn add(&mut self, other: &Self) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0;
+        for (idx, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb + other.limbs.get(idx).copied().unwrap_or(0) + carry;
+            *limb = sum % Self::BASE;
+            carry = sum / Self::BASE;
+        }
+        if carry > 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl Display for BigCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+        write!(f, "{}", limbs.next().copied().unwrap_or(0))?;
+        for limb in limbs {
+            write!(f, "{:018}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of stones of each number.
+pub type Histogram<C> = HashMap<u64, C>;
+
+impl Rules {
+    fn apply(&self, stone: u64) -> Vec<u64> {
+        self.0
+            .iter()
+            .find_map(|rule| rule.apply(stone))
+            .unwrap_or_else(|| vec![stone])
+    }
+
+    /// Blinks once. Stones with the same number always change the same way, so each number is
+    /// only worked out once however many stones carry it.
+    pub fn blink<C: Count>(&self, histogram: &Histogram<C>) -> Histogram<C> {
+        let mut next = Histogram::with_capacity(histogram.len() * 2);
+        for (stone, count) in histogram {
+            for stone in self.apply(*stone) {
+                next.entry(stone).or_insert_with(C::default).add(count);
+            }
+        }
+        next
+    }
+
+    /// The histogram of the stones before the first blink, then after every blink.
+    pub fn evolve<'a, C: Count + 'a>(
+        &'a self,
+        stones: &[u64],
+    ) -> impl Iterator<Item = Histogram<C>> + 'a {
+        let mut histogram = Histogram::new();
+        for stone in stones {
+            histogram
+                .entry(*stone)
+                .or_insert_with(C::default)
+                .add(&C::one());
+        }
+
+        std::iter::successors(Some(histogram), move |histogram| {
+            Some(self.blink(histogram))
+        })
+    }
+
+    /// The number of stones after `blinks` blinks.
+    pub fn count<C: Count>(&self, stones: &[u64], blinks: usize) -> C {
+        let histogram = self
+            .evolve::<C>(stones)
+            .nth(blinks)
+            .expect("evolving never ends");
+        histogram.values().fold(C::default(), |mut total, count| {
+            total.add(count);
+            total
+        })
+    }
+}
+
+fn solve(stones: &[u64], blink: usize) -> String {
+    Rules::default().count::<u64>(stones, blink).to_string()
+}
+
+fn p1(stones: &[u64]) -> String {
+    solve(stones, 25)
+}
+
+fn p2(stones: &[u64]) -> String {
+    solve(stones, 75)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1};
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(solve(&parse_input("0 1 10 99 999").unwrap(), 1), "7");
+        assert_eq!(solve(&parse_input("125 17").unwrap(), 6), "22");
+        assert_eq!(part1(Day11, "125 17"), "55312");
+    }
+
+    #[test]
+    fn test_histogram() {
+        let rules = Rules::default();
+        // 2097446912 14168 4048 2 0 2 4 40 48 2024 40 48 80 96 2 8 6 7 6 0 3 2
+        let histogram = rules.evolve::<u64>(&[125, 17]).nth(6).unwrap();
+        assert_eq!(histogram.len(), 15);
+        assert_eq!(histogram[&2], 4);
+        assert_eq!(histogram[&40], 2);
+        assert_eq!(histogram[&2097446912], 1);
+
+        let rules = Rules(vec![Rule::Replace { from: 1, to: 5 }, Rule::Multiply(3)]);
+        let histogram = rules.evolve::<u64>(&[1, 1, 2]).nth(2).unwrap();
+        assert_eq!(histogram.len(), 2);
+        assert_eq!((histogram[&15], histogram[&18]), (2, 1));
+    }
+
+    #[test]
+    fn test_big_count() {
+        let mut count = BigCount::one();
+        for _ in 0..70 {
+            count.add(&count.clone());
+        }
+        assert_eq!(count.to_string(), (1u128 << 70).to_string());
+        assert_eq!(BigCount::default().to_string(), "0");
+
+        let rules = Rules::default();
+        assert_eq!(
+            rules.count::<BigCount>(&[125, 17], 150).to_string(),
+            rules.count::<u128>(&[125, 17], 150).to_string()
+        );
+        let count = rules.count::<BigCount>(&[125, 17], 1000).to_string();
+        assert!(count.len() > 150, "{}", count);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day11, "125 17x\n"),
+            "line 1, column 5: expected a number, found \"17x\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day11);
+}
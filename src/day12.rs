@@ -0,0 +1,253 @@
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+
+use glam::IVec2;
+
+use crate::{grid::Grid, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pricing {
+    ByPerimeter,
+    BySides,
+}
+
+fn solve(grid: &Grid<char>, pricing: Pricing) -> usize {
+    let mut region_ids = grid.map(|_| -1);
+
+    let mut next_id = 1;
+
+    for pos in grid.positions() {
+        if region_ids[pos] == -1 {
+            let current_id = next_id;
+            next_id += 1;
+
+            fn flood_fill(
+                grid: &Grid<char>,
+                region_ids: &mut Grid<i32>,
+                current_id: i32,
+                pos: IVec2,
+            ) {
+                region_ids[pos] = current_id;
+
+                for next_pos in grid.neighbours_4(pos) {
+                    if region_ids[next_pos] == -1 && grid[next_pos] == grid[pos] {
+                        flood_fill(grid, region_ids, current_id, next_pos);
+                    }
+                }
+            }
+
+            flood_fill(grid, &mut region_ids, current_id, pos);
+        }
+    }
+
+    let mut areas: HashMap<i32, usize> = HashMap::new();
+    let mut perimeters: HashMap<i32, usize> = HashMap::new();
+    let mut sides: HashMap<i32, usize> = HashMap::new();
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Fence {
+        Top,
+        Bottom,
+        Left,
+        Right,
+    }
+
+    let mut fences: HashMap<IVec2, HashSet<(i32, Fence)>> = HashMap::new();
+    let empty_hashset: HashSet<(i32, Fence)> = HashSet::new();
+
+    // for each fence: where the other region is, and which neighbour (already visited, as the
+    // grid is scanned row by row) continues the same side
+    let fence_kinds = [
+        (Fence::Left, IVec2::new(-1, 0), IVec2::new(0, -1)),
+        (Fence::Top, IVec2::new(0, -1), IVec2::new(-1, 0)),
+        (Fence::Right, IVec2::new(1, 0), IVec2::new(0, -1)),
+        (Fence::Bottom, IVec2::new(0, 1), IVec2::new(-1, 0)),
+    ];
+
+    region_ids.iter().for_each(|(pos, id)| {
+        *areas.entry(*id).or_default() += 1;
+
+        fence_kinds.iter().for_each(|(fence, outside, previous)| {
+            if region_ids.get(pos + *outside) != Some(id) {
+                fences.entry(pos).or_default().insert((*id, *fence));
+                *perimeters.entry(*id).or_default() += 1;
+
+                if !fences
+                    .get(&(pos + *previous))
+                    .unwrap_or(&empty_hashset)
+                    .contains(&(*id, *fence))
+                {
+                    *sides.entry(*id).or_default() += 1;
+                }
+            }
+        });
+    });
+
+    let measurements = match pricing {
+        Pricing::ByPerimeter => perimeters,
+        Pricing::BySides => sides,
+    };
+
+    measurements
+        .into_iter()
+        .map(|(id, measurement)| *areas.get(&id).unwrap_or(&0) * measurement)
+        .sum::<usize>()
+}
+
+fn p1(grid: &Grid<char>) -> String {
+    solve(grid, Pricing::ByPerimeter).to_string()
+}
+
+fn p2(grid: &Grid<char>) -> String {
+    solve(grid, Pricing::BySides).to_string()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{part1, part2};
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(
+            part1(
+                Day12,
+                r"
+AAAA
+BBCD
+BBCC
+EEEC
+"
+            ),
+            "140"
+        );
+        assert_eq!(
+            part1(
+                Day12,
+                r"
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+"
+            ),
+            "772"
+        );
+        assert_eq!(
+            part1(
+                Day12,
+                r"
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+"
+            ),
+            "1930"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            part2(
+                Day12,
+                r"
+AAAA
+BBCD
+BBCC
+EEEC
+"
+            ),
+            "80"
+        );
+        assert_eq!(
+            part2(
+                Day12,
+                r"
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+"
+            ),
+            "436"
+        );
+        assert_eq!(
+            part2(
+                Day12,
+                r"
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+"
+            ),
+            "1206"
+        );
+        assert_eq!(
+            part2(
+                Day12,
+                r"
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+"
+            ),
+            "236"
+        );
+        assert_eq!(
+            part2(
+                Day12,
+                r"
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+"
+            ),
+            "368"
+        );
+    }
+
+    crate::answers::actual_tests!(Day12);
+}
@@ -0,0 +1,157 @@
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+// a_1 * x + b_1 * y = c_1
+// a_2 * x + b_2 * y = c_2
+fn solve_sim_eq(a_1: i64, b_1: i64, c_1: i64, a_2: i64, b_2: i64, c_2: i64) -> Option<(i64, i64)> {
+    let det = a_1 * b_2 - a_2 * b_1;
+    let top = c_1 * b_2 + c_2 * -b_1;
+    let bot = c_1 * -a_2 + c_2 * a_1;
+
+    if det == 0 || top % det != 0 || bot % det != 0 {
+        None
+    } else {
+        Some((top / det, bot / det))
+    }
+}
+
+// parses "<label>X<sign>xxx, Y<sign>xxx"
+fn parse_coords(line: &str, label: &str, sign: char) -> Result<(i64, i64), ParseError> {
+    let (x, y) = parse::split_once(parse::strip_prefix(line, label)?, ", ")?;
+
+    Ok((
+        parse::number(parse::strip_prefix(x, &format!("X{}", sign))?)?,
+        parse::number(parse::strip_prefix(y, &format!("Y{}", sign))?)?,
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|test_case| {
+            let mut lines = test_case.lines();
+            let mut next_coords = |label, sign| {
+                let line = lines.next().unwrap_or(parse::end_of(test_case));
+                parse_coords(line, label, sign)
+            };
+
+            let machine = Machine {
+                a: next_coords("Button A: ", '+')?,
+                b: next_coords("Button B: ", '+')?,
+                prize: next_coords("Prize: ", '=')?,
+            };
+
+            match lines.next() {
+                Some(line) => Err(ParseError::new(
+                    line,
+                    "expected a blank line after the prize",
+                )),
+                None => Ok(machine),
+            }
+        })
+        .collect()
+}
+
+fn solve(machines: &[Machine], prize_correction: i64) -> i64 {
+    machines
+        .iter()
+        .flat_map(|machine| {
+            solve_sim_eq(
+                machine.a.0,
+                machine.b.0,
+                machine.prize.0 + prize_correction,
+                machine.a.1,
+                machine.b.1,
+                machine.prize.1 + prize_correction,
+            )
+        })
+        .map(|(a, b)| a * 3 + b)
+        .sum::<i64>()
+}
+
+fn p1(machines: &[Machine]) -> String {
+    solve(machines, 0).to_string()
+}
+
+fn p2(machines: &[Machine]) -> String {
+    solve(machines, 10000000000000).to_string()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Machine>;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1};
+
+    const SAMPLE_INPUT: &str = r"
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day13, SAMPLE_INPUT), "480");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day13, "Button A: X+94, Y+34\nButton B: X+22, Y+67\n"),
+            "line 2, column 21: expected \"Prize: \""
+        );
+        assert_eq!(
+            parse_error(Day13, "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\nPrize: X=1, Y=1\n"),
+            "line 4, column 1: expected a blank line after the prize, found \"Prize: X=1, Y=1\""
+        );
+        assert_eq!(
+            parse_error(
+                Day13,
+                "Button A: X+94, Y=34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n"
+            ),
+            "line 1, column 17: expected \"Y+\", found \"Y=34\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day13);
+}
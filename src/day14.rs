@@ -0,0 +1,435 @@
+use std::ops::Range;
+
+use glam::IVec2;
+
+use crate::{animation::Frame, params::Params, parse, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: (i64, i64),
+    vel: (i64, i64),
+}
+
+trait AdvanceSimulation {
+    fn advance(self, bathroom_size: (i64, i64), time: i64) -> Self;
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (p, v) = parse::split_once(line.trim(), " ")?;
+
+            fn parse_vec2i(input: &str, label: &str) -> Result<(i64, i64), ParseError> {
+                let (x, y) = parse::split_once(parse::strip_prefix(input, label)?, ",")?;
+                Ok((parse::number(x)?, parse::number(y)?))
+            }
+
+            Ok(Robot {
+                pos: parse_vec2i(p, "p=")?,
+                vel: parse_vec2i(v, "v=")?,
+            })
+        })
+        .collect()
+}
+
+impl AdvanceSimulation for Vec<Robot> {
+    fn advance(self, bathroom_size: (i64, i64), time: i64) -> Self {
+        self.into_iter()
+            .map(|robot| Robot {
+                pos: (
+                    (robot.pos.0 + (robot.vel.0 * time)).rem_euclid(bathroom_size.0),
+                    (robot.pos.1 + (robot.vel.1 * time)).rem_euclid(bathroom_size.1),
+                ),
+                vel: robot.vel,
+            })
+            .collect()
+    }
+}
+
+/// The room and the horizon of part 1, which differ between the sample and the actual puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub bathroom_size: (i64, i64),
+    pub time: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            bathroom_size: (101, 103),
+            time: 100,
+        }
+    }
+}
+
+impl Config {
+    fn from_params(params: &Params) -> Result<Self, ParseError> {
+        let default = Self::default();
+        let config = Self {
+            bathroom_size: (
+                params.get("width", default.bathroom_size.0)?,
+                params.get("height", default.bathroom_size.1)?,
+            ),
+            time: params.get("time", default.time)?,
+        };
+
+        if config.bathroom_size.0 <= 0 || config.bathroom_size.1 <= 0 {
+            return Err(ParseError::new("", "the room must not be empty"));
+        }
+        Ok(config)
+    }
+}
+
+pub struct Input {
+    robots: Vec<Robot>,
+    config: Config,
+}
+
+fn solve_p1(
+    robots: &[Robot],
+    Config {
+        bathroom_size,
+        time,
+    }: Config,
+) -> String {
+    let find_quadrant = |pos: (i64, i64)| -> Option<usize> {
+        let mid = (bathroom_size.0 / 2, bathroom_size.1 / 2);
+
+        match pos.0.cmp(&mid.0) {
+            std::cmp::Ordering::Less => match pos.1.cmp(&mid.1) {
+                std::cmp::Ordering::Less => Some(0),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(2),
+            },
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => match pos.1.cmp(&mid.1) {
+                std::cmp::Ordering::Less => Some(1),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(3),
+            },
+        }
+    };
+
+    robots
+        .to_vec()
+        .advance(bathroom_size, time)
+        .into_iter()
+        .map(|robot| robot.pos)
+        .flat_map(find_quadrant)
+        .fold([0, 0, 0, 0], |mut acc, quadrant| {
+            acc[quadrant] += 1;
+            acc
+        })
+        .into_iter()
+        .product::<usize>()
+        .to_string()
+}
+
+fn p1(input: &Input) -> String {
+    solve_p1(&input.robots, input.config)
+}
+
+fn frame(robots: &[Robot], bathroom_size: (i64, i64), step: usize) -> Frame {
+    Frame::from_points(
+        step,
+        bathroom_size.0 as usize,
+        bathroom_size.1 as usize,
+        robots
+            .iter()
+            .map(|robot| IVec2::new(robot.pos.0 as i32, robot.pos.1 as i32)),
+    )
+}
+
+/// The robots at each of `steps`, advancing them one second at a time.
+fn frames(input: &Input, steps: Range<usize>) -> impl Iterator<Item = Frame> + '_ {
+    let bathroom_size = input.config.bathroom_size;
+    let mut current = input
+        .robots
+        .to_vec()
+        .advance(bathroom_size, steps.start as i64);
+
+    steps.map(move |step| {
+        let frame = frame(&current, bathroom_size, step);
+        current = std::mem::take(&mut current).advance(bathroom_size, 1);
+        frame
+    })
+}
+
+/// The frame with the picture of a christmas tree, and how much it stands out from the other
+/// frames, from 0 (not at all) to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tree {
+    pub iteration: i64,
+    pub confidence: f64,
+}
+
+fn variance(values: impl Iterator<Item = i64> + Clone) -> f64 {
+    let (count, sum) = values.clone().fold((0.0, 0.0), |(count, sum), value| {
+        (count + 1.0, sum + value as f64)
+    });
+    let mean = sum / count;
+    values
+        .map(|value| (value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Finds the tree without looking for its shape: the robots that draw it are bunched together, so
+/// the variance of their x positions is lowest at the right time modulo the width, and likewise
+/// for y modulo the height. When the width and height are coprime, the two times are combined with
+/// the chinese remainder theorem. Otherwise not every pair of times happens, so every time until
+/// the robots repeat is scanned for the lowest variances relative to their averages.
+fn find_tree(robots: &[Robot], bathroom_size: (i64, i64)) -> Tree {
+    let (width, height) = bathroom_size;
+    let mut current = robots.to_vec();
+    let mut x_variances = vec![];
+    let mut y_variances = vec![];
+
+    for time in 0..width.max(height) {
+        if time < width {
+            x_variances.push(variance(current.iter().map(|robot| robot.pos.0)));
+        }
+        if time < height {
+            y_variances.push(variance(current.iter().map(|robot| robot.pos.1)));
+        }
+        current = current.advance(bathroom_size, 1);
+    }
+
+    // how far the variance at each time of an axis is below the average one
+    let confidences = |variances: &[f64]| {
+        let mean = variances.iter().sum::<f64>() / variances.len() as f64;
+        variances
+            .iter()
+            .map(|variance| {
+                if mean > 0.0 {
+                    1.0 - variance / mean
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let (x_confidences, y_confidences) = (confidences(&x_variances), confidences(&y_variances));
+    // the earliest of the best times, as `max_by` keeps the last of equal ones
+    let best = |times: Range<i64>, score: &dyn Fn(i64) -> f64| {
+        times
+            .rev()
+            .max_by(|a, b| score(*a).total_cmp(&score(*b)))
+            .expect("the room is not empty")
+    };
+    let x_time = best(0..width, &|time| x_confidences[time as usize]);
+    let y_time = best(0..height, &|time| y_confidences[time as usize]);
+
+    let period = width / gcd(width, height) * height;
+    let time = if gcd(width, height) == 1 {
+        (0..height)
+            .map(|k| x_time + k * width)
+            .find(|time| time % height == y_time)
+            .expect("coprime sizes have a solution")
+    } else {
+        best(0..period, &|time| {
+            x_confidences[(time % width) as usize] + y_confidences[(time % height) as usize]
+        })
+    };
+
+    Tree {
+        // the robots start at time 0, so a picture there is seen again after a full period
+        iteration: if time == 0 { period } else { time },
+        confidence: x_confidences[(time % width) as usize]
+            .min(y_confidences[(time % height) as usize]),
+    }
+}
+
+fn p2(input: &Input) -> String {
+    let bathroom_size = input.config.bathroom_size;
+    let tree = find_tree(&input.robots, bathroom_size);
+
+    if crate::trace::enabled() {
+        let robots = input.robots.to_vec().advance(bathroom_size, tree.iteration);
+        crate::trace!(
+            "Iteration {} (confidence {:.2})",
+            tree.iteration,
+            tree.confidence
+        );
+        crate::trace!(
+            "{}",
+            frame(&robots, bathroom_size, tree.iteration as usize).to_ascii()
+        );
+    }
+
+    tree.iteration.to_string()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        self.parse_with(input, &Params::default())
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        &["width", "height", "time"]
+    }
+
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        Ok(Input {
+            robots: parse_input(input)?,
+            config: Config::from_params(params)?,
+        })
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+
+    fn frames<'a>(
+        &'a self,
+        parsed: &'a Self::Parsed,
+        steps: Range<usize>,
+    ) -> Option<Box<dyn Iterator<Item = Frame> + 'a>> {
+        Some(Box::new(frames(parsed, steps)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+
+    const SAMPLE_INPUT: &str = r"
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    fn sample_params() -> Params {
+        let mut params = Params::default();
+        params.insert_arg("width=11").unwrap();
+        params.insert_arg("height=7").unwrap();
+        params
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        let input = Day14.parse_with(SAMPLE_INPUT, &sample_params()).unwrap();
+        assert_eq!(input.config.bathroom_size, (11, 7));
+        assert_eq!(Day14.part1(&input), "12");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        let input = Day14.parse_with(SAMPLE_INPUT, &sample_params()).unwrap();
+        // the sample draws no tree, but the robots are the most bunched up at second 24 of 77
+        assert_eq!(Day14.part2(&input), "24");
+    }
+
+    #[test]
+    fn test_config() {
+        assert_eq!(Day14.parse(SAMPLE_INPUT).unwrap().config, Config::default());
+
+        let mut params = sample_params();
+        params.insert_arg("time=5").unwrap();
+        let input = Day14.parse_with(SAMPLE_INPUT, &params).unwrap();
+        assert_eq!(input.config.time, 5);
+
+        params.insert_arg("width=0").unwrap();
+        assert!(Day14.parse_with(SAMPLE_INPUT, &params).is_err());
+
+        // part 2 scans every second when the sizes are not coprime
+        params.insert_arg("width=12").unwrap();
+        params.insert_arg("height=8").unwrap();
+        let input = Day14.parse_with(SAMPLE_INPUT, &params).unwrap();
+        assert_eq!(input.config.bathroom_size, (12, 8));
+        Day14.part1(&input);
+        let iteration = Day14.part2(&input).parse::<i64>().unwrap();
+        assert!((1..=24).contains(&iteration), "{}", iteration);
+    }
+
+    /// Random robots, and as many again that draw a filled triangle at `iteration`.
+    fn planted_tree(iteration: i64, (width, height): (i64, i64)) -> Vec<Robot> {
+        let mut rng = XorShift::new(0x2024_1214);
+        let mut random = |bound: i64| rng.below(bound as usize) as i64;
+
+        let tree = (0..15).flat_map(|row| (-row..=row).map(move |col| (50 + col, 40 + row)));
+        let noise = (0..225)
+            .map(|_| (random(width), random(height)))
+            .collect::<Vec<_>>();
+        tree.chain(noise)
+            .map(|target| {
+                let vel = (random(width) - width / 2, random(height) - height / 2);
+                Robot {
+                    pos: (
+                        (target.0 - vel.0 * iteration).rem_euclid(width),
+                        (target.1 - vel.1 * iteration).rem_euclid(height),
+                    ),
+                    vel,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_tree() {
+        let size = Config::default().bathroom_size;
+        let tree = find_tree(&planted_tree(6512, size), size);
+        assert_eq!(tree.iteration, 6512);
+        assert!(tree.confidence > 0.4, "confidence {}", tree.confidence);
+
+        let robots = planted_tree(0, size)
+            .into_iter()
+            .skip(225)
+            .collect::<Vec<_>>();
+        let noise = find_tree(&robots, size);
+        assert!(noise.confidence < 0.3, "confidence {}", noise.confidence);
+
+        // the robots repeat every 5100 seconds in a room of 100 by 102
+        let size = (100, 102);
+        let tree = find_tree(&planted_tree(3217, size), size);
+        assert_eq!(tree.iteration, 3217);
+        assert!(tree.confidence > 0.4, "confidence {}", tree.confidence);
+        assert_eq!(find_tree(&planted_tree(0, size), size).iteration, 5100);
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = Day14.parse_with(SAMPLE_INPUT, &sample_params()).unwrap();
+        let frames = frames(&input, 99..101).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].step, 99);
+        assert_eq!(
+            frames[1].to_ascii(),
+            "......*..*.\n...........\n*..........\n.**........\n.....*.....\n...**......\n.*....*...."
+        );
+    }
+
+    crate::answers::actual_tests!(Day14);
+}
@@ -0,0 +1,1157 @@
+use std::{fmt::Display, ops::Range};
+
+use glam::IVec2;
+
+use crate::{
+    animation::{Frame, Replay},
+    direction::Direction,
+    grid::Grid,
+    params::Params,
+    parse, ParseError, Solution,
+};
+
+/// A box of the warehouse, `O` when it is one cell wide and `[]`, `[=]`, `[==]`... when wider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    /// The leftmost cell.
+    pub pos: IVec2,
+    pub width: usize,
+}
+
+impl Block {
+    fn cells(self) -> impl Iterator<Item = IVec2> {
+        (0..self.width as i32).map(move |dx| self.pos + IVec2::new(dx, 0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+    Robot,
+    /// Index in [`Warehouse::blocks`].
+    Block(usize),
+}
+
+/// The warehouse between two moves of the robot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    blocks: Vec<Block>,
+    robot: IVec2,
+}
+
+impl Warehouse {
+    /// Fails if the grid does not have a robot `@`, has a box that is not closed, or has a `]` or
+    /// `=` outside of a box.
+    pub fn new(grid: &Grid<char>) -> Result<Self, ParseError> {
+        let mut blocks = vec![];
+        let mut cells = grid.map(|ch| match ch {
+            '#' => Cell::Wall,
+            '@' => Cell::Robot,
+            _ => Cell::Empty,
+        });
+
+        for (pos, ch) in grid.iter() {
+            let width = match ch {
+                'O' => 1,
+                '[' => {
+                    let end = (1..)
+                        .map(|dx| pos + IVec2::new(dx, 0))
+                        .find(|end| grid.get(*end) != Some(&'='))
+                        .expect("grids are finite");
+                    if grid.get(end) != Some(&']') {
+                        return Err(ParseError::new("", format!("unclosed box at {}", pos)));
+                    }
+                    (end.x - pos.x + 1) as usize
+                }
+                _ => continue,
+            };
+            let block = Block { pos, width };
+            block
+                .cells()
+                .for_each(|cell| cells[cell] = Cell::Block(blocks.len()));
+            blocks.push(block);
+        }
+
+        if let Some((pos, ch)) = grid
+            .iter()
+            .find(|(pos, ch)| matches!(ch, ']' | '=') && cells[*pos] == Cell::Empty)
+        {
+            return Err(ParseError::new("", format!("stray `{}` at {}", ch, pos)));
+        }
+
+        let robot = grid
+            .find(&'@')
+            .ok_or_else(|| ParseError::new("", "expected a robot `@` in the grid"))?;
+        Ok(Self {
+            cells,
+            blocks,
+            robot,
+        })
+    }
+
+    /// Every cell becomes `factor` cells, and so every box becomes `factor` times wider,
+    /// including boxes that are already wide. Part 2 widens by 2.
+    pub fn widen(&self, factor: usize) -> Self {
+        let scale = |pos: IVec2| IVec2::new(pos.x * factor as i32, pos.y);
+        let mut cells =
+            Grid::from_fn(
+                self.cells.width() * factor,
+                self.cells.height(),
+                |pos| match self.cells[IVec2::new(pos.x / factor as i32, pos.y)] {
+                    Cell::Robot => Cell::Empty,
+                    cell => cell,
+                },
+            );
+        let robot = scale(self.robot);
+        cells[robot] = Cell::Robot;
+
+        Self {
+            cells,
+            blocks: self
+                .blocks
+                .iter()
+                .map(|block| Block {
+                    pos: scale(block.pos),
+                    width: block.width * factor,
+                })
+                .collect(),
+            robot,
+        }
+    }
+
+    pub fn robot(&self) -> IVec2 {
+        self.robot
+    }
+
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Moves the robot, pushing the boxes in its way. Returns `None` if a wall blocks the robot or
+    /// one of the boxes, and otherwise where the boxes that moved were, in the order they were
+    /// reached.
+    pub fn step(&mut self, direction: Direction) -> Option<Vec<IVec2>> {
+        let delta = direction.delta();
+
+        // the boxes that move, from the robot outwards, and the cells they are pushed into
+        let mut pushed: Vec<usize> = vec![];
+        let mut front = vec![self.robot + delta];
+        let mut idx = 0;
+        loop {
+            for cell in front {
+                match self.cells.get(cell)? {
+                    Cell::Empty | Cell::Robot => {}
+                    Cell::Wall => return None,
+                    Cell::Block(block) => {
+                        if !pushed.contains(block) {
+                            pushed.push(*block);
+                        }
+                    }
+                }
+            }
+
+            let Some(&block) = pushed.get(idx) else {
+                break;
+            };
+            idx += 1;
+            front = self.blocks[block]
+                .cells()
+                .map(|cell| cell + delta)
+                .collect();
+        }
+
+        pushed.iter().for_each(|&block| {
+            self.blocks[block]
+                .cells()
+                .for_each(|cell| self.cells[cell] = Cell::Empty);
+        });
+        let moved = pushed
+            .into_iter()
+            .map(|block| {
+                let from = self.blocks[block].pos;
+                self.blocks[block].pos += delta;
+                self.blocks[block]
+                    .cells()
+                    .for_each(|cell| self.cells[cell] = Cell::Block(block));
+                from
+            })
+            .collect();
+
+        self.cells[self.robot] = Cell::Empty;
+        self.robot += delta;
+        self.cells[self.robot] = Cell::Robot;
+        Some(moved)
+    }
+
+    /// The sum of the GPS coordinates of the boxes, measured from their left edge.
+    pub fn gps(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| 100 * block.pos.y as usize + block.pos.x as usize)
+            .sum()
+    }
+
+    pub fn to_grid(&self) -> Grid<char> {
+        Grid::from_fn(self.cells.width(), self.cells.height(), |pos| {
+            match self.cells[pos] {
+                Cell::Empty => '.',
+                Cell::Wall => '#',
+                Cell::Robot => '@',
+                Cell::Block(block) => {
+                    let Block { pos: start, width } = self.blocks[block];
+                    match (pos.x - start.x) as usize {
+                        _ if width == 1 => 'O',
+                        0 => '[',
+                        offset if offset == width - 1 => ']',
+                        _ => '=',
+                    }
+                }
+            }
+        })
+    }
+
+    /// Walls, boxes and the robot are increasingly bright.
+    pub fn frame(&self, step: usize) -> Frame {
+        let grid = self.cells.map(|cell| match cell {
+            Cell::Empty => 0,
+            Cell::Wall => 1,
+            Cell::Block(_) => 2,
+            Cell::Robot => 3,
+        });
+        Frame {
+            step,
+            grid,
+            text: Some(self.to_string()),
+        }
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+#[derive(Clone)]
+pub struct Input {
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+    /// Only used by the replay and the animation, the parts always use their own warehouse.
+    widen: usize,
+}
+
+impl Input {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let (grid_text, moves) = parse::split_sections(input.trim())?;
+
+        // boxes of an already widened grid are accepted for the part 2 tests
+        let grid = Grid::parse(grid_text, |ch| {
+            matches!(ch, '#' | 'O' | '.' | '[' | '=' | ']' | '@').then_some(ch)
+        })?;
+
+        let robots = grid.iter().filter(|(_, ch)| **ch == '@').count();
+        if robots != 1 {
+            return Err(ParseError::new(
+                parse::end_of(grid_text),
+                format!(
+                    "expected exactly one robot `@` in the grid, found {}",
+                    robots
+                ),
+            ));
+        }
+
+        grid_text.lines().try_for_each(|line| {
+            let mut open = None;
+            parse::row_chars(line).try_for_each(|(_, ch, text)| {
+                match (ch, open) {
+                    ('[', None) => open = Some(text),
+                    ('=', Some(_)) => {}
+                    (']', Some(_)) => open = None,
+                    ('=' | ']', None) => return Err(parse::unexpected_char(text)),
+                    (_, Some(start)) => {
+                        return Err(ParseError::new(start, "expected a box like [] or [=]"))
+                    }
+                    _ => {}
+                }
+                Ok(())
+            })?;
+            match open {
+                Some(start) => Err(ParseError::new(start, "expected a box like [] or [=]")),
+                None => Ok(()),
+            }
+        })?;
+
+        Ok(Self {
+            warehouse: Warehouse::new(&grid)?,
+            moves: moves
+                .trim()
+                .lines()
+                .flat_map(parse::row_chars)
+                .map(|(_, ch, text)| {
+                    Direction::from_char(ch).ok_or_else(|| parse::unexpected_char(text))
+                })
+                .collect::<Result<_, _>>()?,
+            widen: 1,
+        })
+    }
+
+    fn simulate(&self, factor: usize) -> usize {
+        let mut warehouse = self.warehouse.widen(factor);
+        self.moves.iter().for_each(|direction| {
+            warehouse.step(*direction);
+        });
+        warehouse.gps()
+    }
+
+    /// The warehouse before any move, widened by `--param widen=<FACTOR>`.
+    fn warehouse(&self) -> Warehouse {
+        self.warehouse.widen(self.widen)
+    }
+}
+
+/// The warehouse after each of the moves, from the initial one at step 0.
+fn states(input: &Input) -> impl Iterator<Item = Warehouse> + '_ {
+    let mut warehouse = input.warehouse();
+    let mut moves = input.moves.iter();
+    std::iter::once(warehouse.clone()).chain(std::iter::from_fn(move || {
+        warehouse.step(*moves.next()?);
+        Some(warehouse.clone())
+    }))
+}
+
+struct WarehouseReplay<'a> {
+    warehouse: Warehouse,
+    moves: &'a [Direction],
+    step: usize,
+}
+
+impl Replay for WarehouseReplay<'_> {
+    fn step(&mut self) -> Option<String> {
+        let direction = *self.moves.get(self.step)?;
+        self.step += 1;
+
+        let description = match self.warehouse.step(direction) {
+            None => "blocked".to_string(),
+            Some(boxes) if boxes.is_empty() => "moved".to_string(),
+            Some(boxes) if boxes.len() == 1 => "pushed 1 box".to_string(),
+            Some(boxes) => format!("pushed {} boxes", boxes.len()),
+        };
+        Some(format!(
+            "move {}/{} {}: {}",
+            self.step,
+            self.moves.len(),
+            direction.to_char(),
+            description
+        ))
+    }
+
+    fn render(&self) -> String {
+        self.warehouse.to_string()
+    }
+}
+
+fn p1(input: &Input) -> String {
+    input.simulate(1).to_string()
+}
+
+fn p2(input: &Input) -> String {
+    input.simulate(2).to_string()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse_input(input)
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        &["widen"]
+    }
+
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let widen = params.get("widen", 1)?;
+        if widen == 0 {
+            return Err(ParseError::new("", "boxes cannot be widened by 0"));
+        }
+        Ok(Input {
+            widen,
+            ..Input::parse_input(input)?
+        })
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+
+    fn frames<'a>(
+        &'a self,
+        parsed: &'a Self::Parsed,
+        steps: Range<usize>,
+    ) -> Option<Box<dyn Iterator<Item = Frame> + 'a>> {
+        Some(Box::new(
+            states(parsed)
+                .enumerate()
+                .skip(steps.start)
+                .take(steps.len())
+                .map(|(step, warehouse)| warehouse.frame(step)),
+        ))
+    }
+
+    fn replay<'a>(&'a self, parsed: &'a Self::Parsed) -> Option<Box<dyn Replay + 'a>> {
+        Some(Box::new(WarehouseReplay {
+            warehouse: parsed.warehouse(),
+            moves: &parsed.moves,
+            step: 0,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+    use crate::solution::testing::{part1, part2};
+
+    const SMALLER_EXAMPLE: &str = r"
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGER_EXAMPLE: &str = r"
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day15, SMALLER_EXAMPLE), "2028");
+        assert_eq!(part1(Day15, LARGER_EXAMPLE), "10092");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Input::parse_input("#####\n#@O.#\n#####\n\n<>x^")
+            .err()
+            .unwrap();
+        assert_eq!(err.text, "x");
+        assert!(Input::parse_input("####\n#..#\n####\n\n<>").is_err());
+    }
+
+    #[test]
+    fn test_p2_expand() {
+        let input = Input::parse_input(LARGER_EXAMPLE).unwrap();
+        assert_eq!(
+            input.warehouse.widen(2).to_string(),
+            r"
+####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################
+"
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_p2_gps() {
+        let input = Input::parse_input(
+            r"
+####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################
+
+^
+",
+        )
+        .unwrap();
+        assert_eq!(input.warehouse.gps(), 9021);
+    }
+
+    #[test]
+    fn test_p2_move() {
+        fn move_test_case(idx: usize, input: &str, expected: &str) {
+            let input = Input::parse_input(input).unwrap();
+            let mut warehouse = input.warehouse;
+            input.moves.into_iter().for_each(|direction| {
+                warehouse.step(direction);
+            });
+            assert_eq!(warehouse.to_string(), expected.trim(), "idx: {}", idx);
+        }
+
+        [
+            // 0
+            (
+                r"
+#########
+##..@..##
+#########
+
+<
+",
+                r"
+#########
+##.@...##
+#########
+",
+            ),
+            // 1
+            (
+                r"
+#########
+##..@..##
+#########
+
+>
+",
+                r"
+#########
+##...@.##
+#########
+",
+            ),
+            // 2
+            (
+                r"
+#########
+##..@..##
+##.....##
+#########
+
+v
+",
+                r"
+#########
+##.....##
+##..@..##
+#########
+",
+            ),
+            // 3
+            (
+                r"
+#########
+##.....##
+##..@..##
+#########
+
+^
+",
+                r"
+#########
+##..@..##
+##.....##
+#########
+",
+            ),
+            // 4
+            (
+                r"
+#########
+##.....##
+##..@#.##
+#########
+
+>
+",
+                r"
+#########
+##.....##
+##..@#.##
+#########
+",
+            ),
+            // 5
+            (
+                r"
+#############
+##.........##
+##..@[][][]##
+##.........##
+#############
+
+>
+",
+                r"
+#############
+##.........##
+##..@[][][]##
+##.........##
+#############
+",
+            ),
+            // 6
+            (
+                r"
+#############
+##.........##
+##..@[][]..##
+##.........##
+#############
+
+>
+",
+                r"
+#############
+##.........##
+##...@[][].##
+##.........##
+#############
+",
+            ),
+            // 7
+            (
+                r"
+#############
+##.........##
+##..@[].#..##
+##.........##
+#############
+
+>
+",
+                r"
+#############
+##.........##
+##...@[]#..##
+##.........##
+#############
+",
+            ),
+            // 8
+            (
+                r"
+#############
+##.........##
+##[][][]@..##
+##.........##
+#############
+
+<
+",
+                r"
+#############
+##.........##
+##[][][]@..##
+##.........##
+#############
+",
+            ),
+            // 9
+            (
+                r"
+#############
+##.........##
+##..[][]@..##
+##.........##
+#############
+
+<
+",
+                r"
+#############
+##.........##
+##.[][]@...##
+##.........##
+#############
+",
+            ),
+            // 10
+            (
+                r"
+#############
+##.........##
+##..#.[]@..##
+##.........##
+#############
+
+<
+",
+                r"
+#############
+##.........##
+##..#[]@...##
+##.........##
+#############
+",
+            ),
+            // 11
+            (
+                r"
+#############
+##...#.....##
+##...[]....##
+##....@....##
+##.........##
+#############
+
+^
+",
+                r"
+#############
+##...#.....##
+##...[]....##
+##....@....##
+##.........##
+#############
+",
+            ),
+            // 12
+            (
+                r"
+#############
+##..#..#...##
+##...[]....##
+##....@....##
+##.........##
+#############
+
+^
+",
+                r"
+#############
+##..#[]#...##
+##....@....##
+##.........##
+##.........##
+#############
+",
+            ),
+            // 13
+            (
+                r"
+#############
+##..#..#...##
+##...[]....##
+##...[]....##
+##...@.....##
+#############
+
+^
+",
+                r"
+#############
+##..#[]#...##
+##...[]....##
+##...@.....##
+##.........##
+#############
+",
+            ),
+            // 14
+            (
+                r"
+#############
+##.#....#..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+
+^
+",
+                r"
+#############
+##.#[][]#..##
+##...[]....##
+##....@....##
+##.........##
+#############
+",
+            ),
+            // 15
+            (
+                r"
+#############
+##.##...#..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+
+^
+",
+                r"
+#############
+##.##...#..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+",
+            ),
+            // 16
+            (
+                r"
+#############
+##.#...##..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+
+^
+",
+                r"
+#############
+##.#...##..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+",
+            ),
+            // 17
+            (
+                r"
+#############
+###..##..####
+##.[]..[]..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+
+^
+",
+                r"
+#############
+###[]##[]####
+##..[][]...##
+##...[]....##
+##....@....##
+##.........##
+#############
+",
+            ),
+            // 18
+            (
+                r"
+#############
+###..##.#####
+##.[]..[]..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+
+^
+",
+                r"
+#############
+###..##.#####
+##.[]..[]..##
+##..[][]...##
+##...[]....##
+##....@....##
+#############
+",
+            ),
+            // 19
+            (
+                r"
+##############
+#............#
+#..[].[].[]..#
+#...[]..[]...#
+#....[][]....#
+#.....[].....#
+#......@.....#
+##############
+
+^
+",
+                r"
+##############
+#..[]....[]..#
+#...[][][]...#
+#....[][]....#
+#.....[].....#
+#......@.....#
+#............#
+##############
+",
+            ),
+            // 20: we are too lazy to repeat all "up" cases for the "down" cases, we assume that
+            //     the logic is the "same". So we only have two "down" cases here.
+            (
+                r"
+##############
+#......@.....#
+#...[][][]...#
+#....[][]....#
+#.....[].....#
+#............#
+##############
+
+v
+",
+                r"
+##############
+#............#
+#...[].@[]...#
+#.....[].....#
+#....[][]....#
+#.....[].....#
+##############
+",
+            ),
+            // 21: second "down" case.
+            (
+                r"
+##############
+#......@.....#
+#...[][][]...#
+#....[][]....#
+#.....[].....#
+#......#.....#
+##############
+
+v
+",
+                r"
+##############
+#......@.....#
+#...[][][]...#
+#....[][]....#
+#.....[].....#
+#......#.....#
+##############
+",
+            ),
+        ]
+        .iter()
+        .enumerate()
+        .for_each(|(idx, (input, expected))| {
+            move_test_case(idx, input, expected);
+        });
+    }
+
+    #[test]
+    fn test_warehouse_step() {
+        let input = Input::parse_input("#######\n#.@O.O#\n#######\n\n>").unwrap();
+        let mut warehouse = input.warehouse;
+        assert_eq!(warehouse.step(Direction::Left), Some(vec![]));
+        assert_eq!(warehouse.robot(), IVec2::new(1, 1));
+        assert_eq!(warehouse.step(Direction::Left), None);
+        assert_eq!(warehouse.step(Direction::Right), Some(vec![]));
+        assert_eq!(
+            warehouse.step(Direction::Right),
+            Some(vec![IVec2::new(3, 1)])
+        );
+        assert_eq!(warehouse.to_string(), "#######\n#..@OO#\n#######");
+        assert_eq!(warehouse.step(Direction::Right), None);
+        assert_eq!(warehouse.gps(), 104 + 105);
+
+        let input =
+            Input::parse_input("######\n#....#\n#.[].#\n#..[]#\n#..@.#\n######\n\n^").unwrap();
+        let mut warehouse = input.warehouse;
+        assert_eq!(
+            warehouse.step(Direction::Up),
+            Some(vec![IVec2::new(3, 3), IVec2::new(2, 2)])
+        );
+        assert_eq!(warehouse.to_grid()[IVec2::new(2, 1)], '[');
+    }
+
+    #[test]
+    fn test_widen() {
+        let input = Input::parse_input("#####\n#@O.#\n#####\n\n>").unwrap();
+        let mut warehouse = input.warehouse.widen(3);
+        assert_eq!(
+            warehouse.to_string(),
+            "###############\n###@..[=]...###\n###############"
+        );
+        assert_eq!(
+            warehouse.blocks(),
+            [Block {
+                pos: IVec2::new(6, 1),
+                width: 3
+            }]
+        );
+        assert_eq!(warehouse.step(Direction::Right), Some(vec![]));
+        assert_eq!(warehouse.step(Direction::Right), Some(vec![]));
+        assert_eq!(
+            warehouse.step(Direction::Right),
+            Some(vec![IVec2::new(6, 1)])
+        );
+        assert_eq!(
+            warehouse.to_string(),
+            "###############\n###...@[=]..###\n###############"
+        );
+        assert_eq!(warehouse.gps(), 107);
+
+        assert!(Warehouse::new(&Grid::parse_chars("#@[=#").unwrap()).is_err());
+        assert!(Warehouse::new(&Grid::parse_chars("#.[]#").unwrap()).is_err());
+        assert!(Warehouse::new(&Grid::parse_chars("#@].#").unwrap()).is_err());
+        assert!(Warehouse::new(&Grid::parse_chars("#@[]=#").unwrap()).is_err());
+        assert!(Input::parse_input("#####\n#@[=#\n#####\n\n>").is_err());
+        assert!(Input::parse_input("#####\n#@].#\n#####\n\n>").is_err());
+        assert!(Input::parse_input("#####\n#@[.]#\n#####\n\n>").is_err());
+    }
+
+    #[test]
+    fn test_widen_widened() {
+        let input = "#######\n#@[].O#\n#######\n\n>>";
+        let parsed = Input::parse_input(input).unwrap();
+        assert_eq!(
+            parsed.warehouse.widen(2).to_string(),
+            "##############\n##@.[==]..[]##\n##############"
+        );
+        // the `[]` is pushed into the `O`, which is against the wall
+        assert_eq!(part1(Day15, input), (103 + 105).to_string());
+        assert_eq!(part2(Day15, input), (105 + 110).to_string());
+    }
+
+    #[test]
+    fn test_push_preserves_walls_and_boxes() {
+        let mut rng = XorShift::new(0x1515);
+        let mut random = |bound: usize| rng.below(bound);
+
+        for _ in 0..20 {
+            let (width, height) = (6 + random(8), 5 + random(6));
+            let mut grid = Grid::from_fn(width, height, |pos| {
+                let border = pos.x == 0
+                    || pos.y == 0
+                    || pos.x as usize == width - 1
+                    || pos.y as usize == height - 1;
+                match random(10) {
+                    _ if border => '#',
+                    0 => '#',
+                    1..=3 => 'O',
+                    _ => '.',
+                }
+            });
+            let empty = grid
+                .iter()
+                .filter(|(_, ch)| **ch == '.')
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>();
+            if empty.is_empty() {
+                continue;
+            }
+            grid[empty[random(empty.len())]] = '@';
+            let moves = (0..200)
+                .map(|_| Direction::ALL[random(4)])
+                .collect::<Vec<_>>();
+
+            for factor in 1..=3 {
+                let mut warehouse = Warehouse::new(&grid).unwrap().widen(factor);
+                let walls = |warehouse: &Warehouse| {
+                    warehouse
+                        .to_grid()
+                        .iter()
+                        .filter(|(_, ch)| **ch == '#')
+                        .map(|(pos, _)| pos)
+                        .collect::<Vec<_>>()
+                };
+                let widths = |warehouse: &Warehouse| {
+                    let mut widths = warehouse
+                        .blocks()
+                        .iter()
+                        .map(|block| block.width)
+                        .collect::<Vec<_>>();
+                    widths.sort_unstable();
+                    widths
+                };
+                let (initial_walls, initial_widths) = (walls(&warehouse), widths(&warehouse));
+
+                moves.iter().for_each(|&direction| {
+                    let before = warehouse.clone();
+                    match warehouse.step(direction) {
+                        Some(_) => {
+                            assert_eq!(warehouse.robot(), before.robot() + direction.delta())
+                        }
+                        None => assert_eq!(warehouse, before),
+                    }
+
+                    assert_eq!(walls(&warehouse), initial_walls);
+                    assert_eq!(widths(&warehouse), initial_widths);
+                    // the boxes do not overlap, so they are all drawn whole
+                    assert_eq!(
+                        widths(&Warehouse::new(&warehouse.to_grid()).unwrap()),
+                        initial_widths
+                    );
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_replay_and_frames() {
+        let input = Day15
+            .parse_with(SMALLER_EXAMPLE, &{
+                let mut params = Params::default();
+                params.insert_arg("widen=2").unwrap();
+                params
+            })
+            .unwrap();
+        let mut replay = Day15.replay(&input).unwrap();
+        assert_eq!(replay.step().unwrap(), "move 1/15 <: blocked");
+        assert_eq!(replay.render().lines().next().unwrap().len(), 16);
+        assert_eq!((0..20).map_while(|_| replay.step()).count(), 14);
+
+        let frames = Day15.frames(&input, 10..100).unwrap().collect::<Vec<_>>();
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[5].step, 15);
+        assert_eq!(frames[5].text.as_deref(), Some(replay.render().as_str()));
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(Day15, LARGER_EXAMPLE), "9021");
+    }
+
+    crate::answers::actual_tests!(Day15);
+}
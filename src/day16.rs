@@ -0,0 +1,190 @@
+use ahash::HashSet;
+
+use glam::IVec2;
+
+use crate::{
+    direction::Direction,
+    grid::Grid,
+    parse,
+    search::{self, Predecessors, Search},
+    ParseError, Solution,
+};
+
+pub struct Map {
+    grid: Grid<char>,
+    start: IVec2,
+    end: IVec2,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::parse(input, |ch| {
+            matches!(ch, '#' | '.' | 'S' | 'E').then_some(ch)
+        })?;
+
+        let missing = |character| {
+            ParseError::new(
+                parse::end_of(input),
+                format!("expected {} in the map", character),
+            )
+        };
+        let start = grid.find(&'S').ok_or_else(|| missing('S'))?;
+        let end = grid.find(&'E').ok_or_else(|| missing('E'))?;
+        grid[start] = '.';
+        grid[end] = '.';
+
+        Ok(Self { grid, start, end })
+    }
+
+    /// Steps in every direction that is not blocked, turning first if needed.
+    fn neighbours(
+        &self,
+        (pos, direction): &(IVec2, Direction),
+    ) -> Vec<((IVec2, Direction), usize)> {
+        Direction::ALL
+            .into_iter()
+            .map(|new_dir| (pos + new_dir.delta(), new_dir))
+            .filter(|(new_pos, _)| self.grid.get(*new_pos) == Some(&'.'))
+            .map(|(new_pos, new_dir)| {
+                let turn_cost = 1000 * direction.rotations_between(new_dir) as usize;
+                ((new_pos, new_dir), 1 + turn_cost)
+            })
+            .collect()
+    }
+
+    fn search(&self, predecessors: Predecessors) -> Search<(IVec2, Direction), usize> {
+        search::dijkstra(
+            (self.start, Direction::Right),
+            |state| self.neighbours(state),
+            |(pos, _)| *pos == self.end,
+            predecessors,
+        )
+    }
+}
+
+fn p1(map: &Map) -> String {
+    map.search(Predecessors::First)
+        .cost()
+        .expect("end is not blocked")
+        .to_string()
+}
+
+fn p2(map: &Map) -> String {
+    map.search(Predecessors::All)
+        .all_optimal_states()
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
+        .to_string()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Map;
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT_1: &str = r"
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const SAMPLE_INPUT_2: &str = r"
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day16, SAMPLE_INPUT_1), "7036");
+        assert_eq!(part1(Day16, SAMPLE_INPUT_2), "11048");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        // shortest path is 2006
+        // at (3, 1), there are two paths.
+        //      - 1 is facing >, cost 2005 [still a valid path, even if not min at this point]
+        //      - 1 is facing ^, cost 1005
+        assert_eq!(
+            part2(
+                Day16,
+                r"
+######
+#...E#
+#.#.##
+#.#.##
+#S..##
+######
+"
+            ),
+            "11"
+        );
+        assert_eq!(part2(Day16, SAMPLE_INPUT_1), "45");
+        assert_eq!(part2(Day16, SAMPLE_INPUT_2), "64");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day16, "#####\n#..E#\n#####\n"),
+            "line 4, column 1: expected S in the map"
+        );
+        assert_eq!(
+            parse_error(Day16, "#####\n#S..#\n#####\n"),
+            "line 4, column 1: expected E in the map"
+        );
+    }
+
+    crate::answers::actual_tests!(Day16);
+}
@@ -1,44 +1,33 @@
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/17/input.txt");
-
-fn parse_input(input: &str) -> (Vec<i64>, Vec<i64>) {
-    input.trim().lines().enumerate().fold(
-        (vec![], vec![]),
-        |(mut reg, program), (line_number, line)| {
-            match line_number {
-                0..=2 => {
-                    reg.push(
-                        line.split(":")
-                            .nth(1)
-                            .expect(" xxxx")
-                            .trim()
-                            .parse::<i64>()
-                            .expect("a number"),
-                    );
-                    (reg, program)
-                }
-                3 => {
-                    // ignore
-                    (reg, program)
-                }
-                4 => (
-                    reg,
-                    line.split(":")
-                        .nth(1)
-                        .expect(" x,x,x,x")
-                        .trim()
-                        .split(",")
-                        .map(|x| x.parse::<i64>().expect("a number"))
-                        .collect(),
-                ),
-                _ => {
-                    panic!(
-                        "Not expecting more than 5 lines for the input, but found line {}, with line {}.",
-                        line_number, line
-                    );
-                }
+use crate::{parse, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let (registers, program) = parse::split_sections(input.trim())?;
+
+    let reg = registers
+        .lines()
+        .zip(["Register A: ", "Register B: ", "Register C: "])
+        .map(|(line, label)| parse::number(parse::strip_prefix(line.trim(), label)?))
+        .collect::<Result<Vec<_>, _>>()?;
+    if reg.len() != 3 {
+        return Err(ParseError::new(
+            parse::end_of(registers),
+            "expected registers A, B and C",
+        ));
+    }
+
+    let program = parse::strip_prefix(program.trim(), "Program: ")?
+        .split(",")
+        .map(|x| {
+            let value = parse::number(x)?;
+            if (0..8).contains(&value) {
+                Ok(value)
+            } else {
+                Err(ParseError::new(x, "expected a 3-bit number"))
             }
-        },
-    )
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((reg, program))
 }
 
 fn execute_program(mut reg: Vec<i64>, program: &[i64]) -> Vec<i64> {
@@ -97,19 +86,18 @@ fn execute_program(mut reg: Vec<i64>, program: &[i64]) -> Vec<i64> {
     out
 }
 
-fn p1(input: &str) -> String {
-    let (reg, program) = parse_input(input);
-    execute_program(reg, &program)
+fn p1((reg, program): &(Vec<i64>, Vec<i64>)) -> String {
+    execute_program(reg.clone(), program)
         .into_iter()
         .map(|num| num.to_string())
         .collect::<Vec<_>>()
         .join(",")
 }
 
-fn p2(input: &str) -> String {
+fn p2((_, program): &(Vec<i64>, Vec<i64>)) -> String {
     /*
     {
-        let (_, program) = parse_input(ACTUAL_INPUT);
+        let (_, program) = parse_input(input);
         // https://www.reddit.com/r/adventofcode/comments/1hg69ql/2024_day_17_part_2_can_someone_please_provide_a/
         println!("{:?}", execute_program(vec![0, 0, 0], &program));
         println!("{:?}", execute_program(vec![1, 0, 0], &program));
@@ -176,8 +164,6 @@ fn p2(input: &str) -> String {
     }
     */
 
-    let (_, program) = parse_input(input);
-
     (0..(program.len() / 2))
         .fold(vec![], |mut acc, i| {
             let ptr = i * 2;
@@ -226,16 +212,36 @@ fn p2(input: &str) -> String {
         .join("\n")
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+
+    fn prints_part2(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::ops::Shr;
-
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const P1_EXAMPLE: &str = r"
 Register A: 729
@@ -255,18 +261,13 @@ Program: 0,3,5,4,3,0
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(P1_EXAMPLE), "4,6,3,5,6,3,5,2,1,0");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "1,5,0,3,7,3,0,3,1");
+        assert_eq!(part1(Day17, P1_EXAMPLE), "4,6,3,5,6,3,5,2,1,0");
     }
 
     #[test]
     fn test_p2_sample() {
         assert_eq!(
-            p2(P1_EXAMPLE),
+            part2(Day17, P1_EXAMPLE),
             r"
 0: A = A >> 1
 2: out(A % 8)
@@ -275,7 +276,7 @@ Program: 0,3,5,4,3,0
             .trim()
         );
         assert_eq!(
-            p2(P2_EXAMPLE),
+            part2(Day17, P2_EXAMPLE),
             r"
 0: A = A >> 3
 2: out(A % 8)
@@ -285,13 +286,16 @@ Program: 0,3,5,4,3,0
         );
 
         assert_eq!(
-            p2(r"
+            part2(
+                Day17,
+                r"
 Register A: 2024
 Register B: 0
 Register C: 0
 
 Program: 0,1,0,4,1,4,2,1,2,5,3,1,4,0,5,3,5,6,6,1,7,1
-"),
+"
+            ),
             r"
 0: A = A >> 1
 2: A = A >> A
@@ -308,36 +312,62 @@ Program: 0,1,0,4,1,4,2,1,2,5,3,1,4,0,5,3,5,6,6,1,7,1
             .trim()
         );
 
-        let (_, program) = parse_input(P2_EXAMPLE);
+        let (_, program) = parse_input(P2_EXAMPLE).unwrap();
         let answer = 117440;
         assert_eq!(execute_program(vec![answer, 0, 0], &program), program);
+        assert_eq!(find_quine(0, 1, &program), Some(answer));
     }
 
+    /// The lowest register A for which `program` prints itself, for programs that shift A by 3
+    /// bits per output: A is built 3 bits at a time, each time printing one more value of the end
+    /// of the program.
+    fn find_quine(reg_a: i64, len: usize, program: &[i64]) -> Option<i64> {
+        (0..8).find_map(|d| {
+            let new_a = reg_a * 8 + d;
+            let output = execute_program(vec![new_a, 0, 0], program);
+            if new_a == 0 || output != program[program.len() - len..] {
+                None
+            } else if len == program.len() {
+                Some(new_a)
+            } else {
+                find_quine(new_a, len + 1, program)
+            }
+        })
+    }
+
+    /// Part 2 prints a disassembly, so the answer in the manifest is checked by running the
+    /// program with it.
     #[test]
     fn test_p2_actual() {
-        let (_, program) = parse_input(ACTUAL_INPUT);
-        let answer = {
-            fn recursive(reg_a: i64, current_idx: usize, program: &[i64]) -> Option<i64> {
-                if current_idx >= program.len() {
-                    Some(reg_a)
-                } else {
-                    (0..8).find_map(|d| {
-                        let new_a = reg_a * 8 + d;
-
-                        if (d ^ 3 ^ (new_a.shr(d ^ 5))) % 8
-                            == program[program.len() - 1 - current_idx]
-                        {
-                            recursive(new_a, current_idx + 1, program)
-                        } else {
-                            None
-                        }
-                    })
-                }
-            }
+        for (profile, input, expected) in crate::answers::actual_answers(17, 2) {
+            let (_, program) = parse_input(&input).unwrap();
+            let answer = find_quine(0, 1, &program).expect("have an answer");
+            assert_eq!(execute_program(vec![answer, 0, 0], &program), program);
+            assert_eq!(answer.to_string(), expected, "profile {}", profile);
+        }
+    }
 
-            recursive(0, 0, &program).expect("have an answer")
-        };
-        assert_eq!(execute_program(vec![answer, 0, 0], &program), program);
-        assert_eq!(answer, 105981155568026);
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(
+                Day17,
+                "Register A: 1\nRegister B: 0\nRegister C: 0\nProgram: 0,1\n"
+            ),
+            "line 4, column 13: expected a blank line between sections"
+        );
+        assert_eq!(
+            parse_error(Day17, "Register A: 1\nRegister B: 0\n\nProgram: 0,1\n"),
+            "line 2, column 14: expected registers A, B and C"
+        );
+        assert_eq!(
+            parse_error(
+                Day17,
+                "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8\n"
+            ),
+            "line 5, column 12: expected a 3-bit number, found \"8\""
+        );
     }
+
+    crate::answers::actual_tests!(Day17, part1_only);
 }
@@ -0,0 +1,154 @@
+use glam::IVec2;
+
+use crate::{
+    grid::Grid,
+    parse,
+    search::{self, Predecessors},
+    ParseError, Solution,
+};
+
+fn parse_input(input: &str) -> Result<Vec<IVec2>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (x, y) = parse::split_once(line.trim(), ",")?;
+            Ok(IVec2::new(parse::number(x)?, parse::number(y)?))
+        })
+        .collect()
+}
+
+fn corrupt(points: &[IVec2], bounds: (usize, usize), bytes_fallen: usize) -> Grid<bool> {
+    let mut corrupted = Grid::new(bounds.0, bounds.1, false);
+    points.iter().take(bytes_fallen).for_each(|point| {
+        if let Some(cell) = corrupted.get_mut(*point) {
+            *cell = true;
+        }
+    });
+    corrupted
+}
+
+fn get_shortest_path(corrupted: &Grid<bool>) -> Option<usize> {
+    let end = corrupted.size() - IVec2::ONE;
+    search::bfs(
+        IVec2::ZERO,
+        |pos| corrupted.neighbours_4(*pos).filter(|pos| !corrupted[*pos]),
+        |pos| *pos == end,
+        Predecessors::First,
+    )
+    .cost()
+}
+
+fn solve_p1(points: &[IVec2], bounds: (usize, usize), bytes_fallen: usize) -> String {
+    get_shortest_path(&corrupt(points, bounds, bytes_fallen))
+        .expect("input should always have an answer")
+        .to_string()
+}
+
+fn p1(points: &[IVec2]) -> String {
+    solve_p1(points, (71, 71), 1024)
+}
+
+fn solve_p2(points: &[IVec2], bounds: (usize, usize), start_bytes_count: usize) -> String {
+    let mut left = start_bytes_count + 1;
+    let mut right = points.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if get_shortest_path(&corrupt(points, bounds, mid)).is_some() {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+
+    let candidate = points[right - 1];
+    format!("{},{}", candidate.x, candidate.y)
+}
+
+fn p2(points: &[IVec2]) -> String {
+    solve_p2(points, (71, 71), 1024)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<IVec2>;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::parse_error;
+
+    const SAMPLE_INPUT: &str = r"
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(
+            solve_p1(&parse_input(SAMPLE_INPUT).unwrap(), (7, 7), 12),
+            "22"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            solve_p2(&parse_input(SAMPLE_INPUT).unwrap(), (7, 7), 12),
+            "6,1"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day18, "5,4\n4;2\n"),
+            "line 2, column 1: expected \",\", found \"4;2\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day18);
+}
@@ -1,32 +1,33 @@
 use ahash::{HashMap, HashMapExt};
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/19/input.txt");
+use crate::{parse, ParseError, Solution};
 
-struct Input<'a> {
-    towels: Vec<&'a str>,
-    patterns: Vec<&'a str>,
+pub struct Input {
+    towels: Vec<String>,
+    patterns: Vec<String>,
 }
 
-fn parse_input(input: &str) -> Input {
-    let (towels, patterns) = input
-        .trim()
-        .split_once("\n\n")
-        .expect("input has two sections");
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let (towels, patterns) = parse::split_sections(input.trim())?;
 
-    let towels = towels.split(",").map(|s| s.trim()).collect::<Vec<_>>();
-    let patterns = patterns.lines().map(|s| s.trim()).collect::<Vec<_>>();
+    let towels = towels
+        .split(",")
+        .map(|s| s.trim().to_string())
+        .collect::<Vec<_>>();
+    let patterns = patterns
+        .lines()
+        .map(|s| s.trim().to_string())
+        .collect::<Vec<_>>();
 
-    Input { towels, patterns }
+    Ok(Input { towels, patterns })
 }
 
-fn p1(input: &str) -> String {
-    let input = parse_input(input);
-
+fn p1(input: &Input) -> String {
     input
         .patterns
-        .into_iter()
+        .iter()
         .filter(|pattern| {
-            fn check(pattern: &str, towels: &[&str], current_idx: usize) -> bool {
+            fn check(pattern: &str, towels: &[String], current_idx: usize) -> bool {
                 if current_idx == pattern.len() {
                     true
                 } else {
@@ -48,18 +49,17 @@ fn p1(input: &str) -> String {
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    let input = parse_input(input);
+fn p2(input: &Input) -> String {
     let mut dp = HashMap::new();
 
     input
         .patterns
-        .into_iter()
+        .iter()
         .map(|pattern| {
             fn count<'a>(
                 dp: &mut HashMap<&'a str, usize>,
                 pattern: &'a str,
-                towels: &[&str],
+                towels: &[String],
                 current_idx: usize,
             ) -> usize {
                 if current_idx == pattern.len() {
@@ -94,14 +94,32 @@ fn p2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"
 r, wr, b, g, bwu, rb, gb, br
@@ -118,21 +136,21 @@ bbrgwb
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "6");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "340");
+        assert_eq!(part1(Day19, SAMPLE_INPUT), "6");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "16");
+        assert_eq!(part2(Day19, SAMPLE_INPUT), "16");
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "717561822679428");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day19, "r, wr, b\nbrwrr\n"),
+            "line 2, column 6: expected a blank line between sections"
+        );
     }
+
+    crate::answers::actual_tests!(Day19);
 }
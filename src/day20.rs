@@ -0,0 +1,289 @@
+use ahash::{HashMap, HashMapExt};
+use glam::IVec2;
+use itertools::Itertools;
+
+use crate::{grid::Grid, parse, ParseError, Solution};
+
+pub struct Input {
+    grid: Grid<char>,
+
+    all_costs: HashMap<IVec2, i64>,
+    path: Vec<IVec2>,
+}
+
+impl Input {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::parse(input, |ch| {
+            matches!(ch, '#' | '.' | 'S' | 'E').then_some(ch)
+        })?;
+
+        let find_ch = |character| {
+            grid.find(&character).ok_or_else(|| {
+                ParseError::new(
+                    parse::end_of(input),
+                    format!("expected {} in the map", character),
+                )
+            })
+        };
+        let start = find_ch('S')?;
+        let end = find_ch('E')?;
+
+        grid[start] = '.';
+        grid[end] = '.';
+
+        let mut all_costs = HashMap::new();
+        let mut path = vec![];
+
+        let mut next_cost = 0;
+        let mut next_node = Some(start);
+
+        while let Some(next) = next_node {
+            all_costs.insert(next, next_cost);
+            path.push(next);
+
+            next_cost += 1;
+            next_node = grid
+                .neighbours_4(next)
+                .filter(|pos| grid[*pos] == '.')
+                .find(|pos| !all_costs.contains_key(pos));
+        }
+
+        Ok(Self {
+            grid,
+
+            all_costs,
+            path,
+        })
+    }
+}
+
+fn solve_p1(input: &Input, limit: i64) -> String {
+    input
+        .grid
+        .iter()
+        .filter(|(pos, ch)| pos.x + 1 < input.grid.width() as i32 && **ch == '#')
+        .filter(|(pair_1, _)| {
+            input
+                .grid
+                .neighbours_4(*pair_1)
+                .filter(|pair_2| input.grid[*pair_2] == '.')
+                .map(|pair_2| input.all_costs.get(&pair_2).expect("visited before"))
+                .permutations(2)
+                .map(|pairs| pairs[0] - pairs[1] - 2)
+                .filter(|diff| *diff >= limit)
+                .count()
+                > 0
+        })
+        .count()
+        .to_string()
+}
+
+fn p1(input: &Input) -> String {
+    solve_p1(input, 100)
+}
+
+fn solve_p2(input: &Input, limit: i64) -> String {
+    input
+        .path
+        .iter()
+        .map(|start_cheat| {
+            (-20i32..=20)
+                .map(|dx| {
+                    (-20i32..=20)
+                        .filter(|dy| dx.abs() + dy.abs() <= 20)
+                        .map(|dy| *start_cheat - IVec2::new(dx, dy))
+                        .filter(|end_cheat| input.grid.get(*end_cheat) == Some(&'.'))
+                        .map(|end_cheat| {
+                            input.all_costs.get(&end_cheat).expect("visited before")
+                                - input.all_costs.get(start_cheat).expect("visited before")
+                                - (*start_cheat - end_cheat).abs().element_sum() as i64
+                        })
+                        .filter(|diff| *diff >= limit)
+                        .count()
+                })
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+        .to_string()
+}
+
+fn p2(input: &Input) -> String {
+    solve_p2(input, 100)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT: &str = r"
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn test_p1_sample() {
+        let test_cases = [
+            (14, 2),
+            (14, 4),
+            (2, 6),
+            (4, 8),
+            (2, 10),
+            (3, 12),
+            (1, 20),
+            (1, 36),
+            (1, 38),
+            (1, 40),
+            (1, 64),
+        ];
+
+        test_cases.iter().enumerate().for_each(|(case_id, case)| {
+            let total_with = test_cases.iter().skip(case_id).map(|c| c.0).sum::<usize>();
+            let total_without = test_cases
+                .iter()
+                .skip(case_id + 1)
+                .map(|c| c.0)
+                .sum::<usize>();
+
+            assert_eq!(
+                solve_p1(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1 - 1),
+                total_with.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1 - 1,
+                total_with
+            );
+
+            assert_eq!(
+                solve_p1(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1),
+                total_with.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1,
+                total_with
+            );
+            assert_eq!(
+                solve_p1(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1 + 1),
+                total_without.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1 + 1,
+                total_with
+            );
+        });
+
+        assert_eq!(part1(Day20, SAMPLE_INPUT), "0");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        let test_cases = [
+            (32, 50),
+            (31, 52),
+            (29, 54),
+            (39, 56),
+            (25, 58),
+            (23, 60),
+            (20, 62),
+            (19, 64),
+            (12, 66),
+            (14, 68),
+            (12, 70),
+            (22, 72),
+            (4, 74),
+            (3, 76),
+        ];
+
+        test_cases.iter().enumerate().for_each(|(case_id, case)| {
+            let total_with = test_cases.iter().skip(case_id).map(|c| c.0).sum::<usize>();
+            let total_without = test_cases
+                .iter()
+                .skip(case_id + 1)
+                .map(|c| c.0)
+                .sum::<usize>();
+
+            assert_eq!(
+                solve_p2(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1 - 1),
+                total_with.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1 - 1,
+                total_with
+            );
+
+            assert_eq!(
+                solve_p2(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1),
+                total_with.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1,
+                total_with
+            );
+            assert_eq!(
+                solve_p2(&Input::parse_input(SAMPLE_INPUT).unwrap(), case.1 + 1),
+                total_without.to_string(),
+                "{} cheats, {} picoseconds test case: {} picoseconds expected {} total",
+                case.0,
+                case.1,
+                case.1 + 1,
+                total_with
+            );
+        });
+
+        assert_eq!(part2(Day20, SAMPLE_INPUT), "0");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day20, "####\n#SE#\n#x.#\n####\n"),
+            "line 3, column 2: unexpected character, found \"x\""
+        );
+        assert_eq!(
+            parse_error(Day20, "####\n#S.#\n####\n"),
+            "line 4, column 1: expected E in the map"
+        );
+    }
+
+    crate::answers::actual_tests!(Day20);
+}
@@ -1,8 +1,15 @@
-use std::{collections::VecDeque, sync::LazyLock};
+use std::sync::LazyLock;
 
-use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use ahash::{HashMap, HashMapExt, HashSet};
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/21/input.txt");
+use glam::IVec2;
+
+use crate::{
+    direction::Direction,
+    grid::Grid,
+    search::{self, Predecessors},
+    ParseError, Solution,
+};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 enum DPad {
@@ -19,105 +26,65 @@ enum NPad {
     A,
 }
 
-trait CoordAdvance
-where
-    Self: Sized,
-{
-    fn up(&self) -> Option<Self>;
-    fn down(&self, bounds: (usize, usize)) -> Option<Self>;
-    fn left(&self) -> Option<Self>;
-    fn right(&self, bounds: (usize, usize)) -> Option<Self>;
-}
-
-impl CoordAdvance for (usize, usize) {
-    fn up(&self) -> Option<Self> {
-        self.1.checked_sub(1).map(|y| (self.0, y))
-    }
-
-    fn down(&self, bounds: (usize, usize)) -> Option<Self> {
-        if self.1 + 1 < bounds.1 {
-            Some((self.0, self.1 + 1))
-        } else {
-            None
+impl DPad {
+    fn direction(&self) -> Option<Direction> {
+        match self {
+            DPad::Up => Some(Direction::Up),
+            DPad::Down => Some(Direction::Down),
+            DPad::Left => Some(Direction::Left),
+            DPad::Right => Some(Direction::Right),
+            DPad::A => None,
         }
     }
+}
 
-    fn left(&self) -> Option<Self> {
-        self.0.checked_sub(1).map(|x| (x, self.1))
-    }
-
-    fn right(&self, bounds: (usize, usize)) -> Option<Self> {
-        if self.0 + 1 < bounds.0 {
-            Some((self.0 + 1, self.1))
-        } else {
-            None
-        }
-    }
+// `#` is the gap in a keypad
+fn keypad<T>(layout: &str, key: impl Fn(char) -> T) -> Grid<Option<T>> {
+    Grid::parse(layout, |ch| Some((ch != '#').then(|| key(ch)))).expect("valid keypad layout")
 }
 
 impl NPad {
     fn handle_dpad_press(&self, dpad: DPad) -> Option<Self> {
-        static POS_TO_NPAD: LazyLock<HashMap<(usize, usize), NPad>> = LazyLock::new(|| {
-            [
-                ((0, 0), NPad::Number(7)),
-                ((1, 0), NPad::Number(8)),
-                ((2, 0), NPad::Number(9)),
-                ((0, 1), NPad::Number(4)),
-                ((1, 1), NPad::Number(5)),
-                ((2, 1), NPad::Number(6)),
-                ((0, 2), NPad::Number(1)),
-                ((1, 2), NPad::Number(2)),
-                ((2, 2), NPad::Number(3)),
-                ((1, 3), NPad::Number(0)),
-                ((2, 3), NPad::A),
-            ]
-            .into_iter()
-            .collect()
+        static KEYPAD: LazyLock<Grid<Option<NPad>>> = LazyLock::new(|| {
+            keypad("789\n456\n123\n#0A", |ch| match ch.to_digit(10) {
+                Some(number) => NPad::Number(number as usize),
+                None => NPad::A,
+            })
         });
-        static NPAD_TO_POS: LazyLock<HashMap<NPad, (usize, usize)>> =
-            LazyLock::new(|| POS_TO_NPAD.iter().map(|(k, v)| (*v, *k)).collect());
-        static BOUNDS: (usize, usize) = (3, 4);
-
-        let current_pos = NPAD_TO_POS.get(self).unwrap();
-        let new_pos = match dpad {
-            DPad::Up => current_pos.up(),
-            DPad::Down => current_pos.down(BOUNDS),
-            DPad::Left => current_pos.left(),
-            DPad::Right => current_pos.right(BOUNDS),
-            DPad::A => return Some(*self),
+
+        let Some(direction) = dpad.direction() else {
+            return Some(*self);
         };
+        let current_pos = KEYPAD.find(&Some(*self)).unwrap();
 
-        new_pos.and_then(|pos| POS_TO_NPAD.get(&pos).copied())
+        KEYPAD
+            .get(current_pos + direction.delta())
+            .copied()
+            .flatten()
     }
 }
 
 impl DPad {
     fn handle_dpad_press(&self, dpad: DPad) -> Option<Self> {
-        static POS_TO_DPAD: LazyLock<HashMap<(usize, usize), DPad>> = LazyLock::new(|| {
-            [
-                ((1, 0), DPad::Up),
-                ((2, 0), DPad::A),
-                ((0, 1), DPad::Left),
-                ((1, 1), DPad::Down),
-                ((2, 1), DPad::Right),
-            ]
-            .into_iter()
-            .collect()
+        static KEYPAD: LazyLock<Grid<Option<DPad>>> = LazyLock::new(|| {
+            keypad("#^A\n<v>", |ch| match Direction::from_char(ch) {
+                Some(Direction::Up) => DPad::Up,
+                Some(Direction::Down) => DPad::Down,
+                Some(Direction::Left) => DPad::Left,
+                Some(Direction::Right) => DPad::Right,
+                None => DPad::A,
+            })
         });
-        static DPAD_TO_POS: LazyLock<HashMap<DPad, (usize, usize)>> =
-            LazyLock::new(|| POS_TO_DPAD.iter().map(|(k, v)| (*v, *k)).collect());
-        static BOUNDS: (usize, usize) = (3, 2);
-
-        let current_pos = DPAD_TO_POS.get(self).unwrap();
-        let new_pos = match dpad {
-            DPad::Up => current_pos.up(),
-            DPad::Down => current_pos.down(BOUNDS),
-            DPad::Left => current_pos.left(),
-            DPad::Right => current_pos.right(BOUNDS),
-            DPad::A => return Some(*self),
+
+        let Some(direction) = dpad.direction() else {
+            return Some(*self);
         };
+        let current_pos = KEYPAD.find(&Some(*self)).unwrap();
 
-        new_pos.and_then(|pos| POS_TO_DPAD.get(&pos).copied())
+        KEYPAD
+            .get(current_pos + direction.delta())
+            .copied()
+            .flatten()
     }
 }
 
@@ -132,7 +99,7 @@ impl StateNode {
     fn start_state(total_dpads: usize) -> Self {
         Self {
             npad: NPad::A,
-            dpads: std::iter::repeat(DPad::A).take(total_dpads).collect(),
+            dpads: std::iter::repeat_n(DPad::A, total_dpads).collect(),
             successes: 0,
         }
     }
@@ -210,32 +177,19 @@ impl StateNode {
 }
 
 fn find_shortest(line: &str, total_dpads: usize) -> usize {
-    let mut visited: HashSet<StateNode> = HashSet::new();
-
-    let mut to_process = [(0, StateNode::start_state(total_dpads))]
-        .into_iter()
-        .collect::<VecDeque<_>>();
-
-    while let Some(next) = to_process.pop_front() {
-        if next.1.is_end_state(line) {
-            return next.0;
-        }
-
-        if visited.contains(&next.1) {
-            continue;
-        }
-
-        visited.insert(next.1.clone());
-
-        [DPad::Up, DPad::Down, DPad::Left, DPad::Right, DPad::A]
-            .into_iter()
-            .flat_map(|human_dpad| next.1.handle_dpad_press(human_dpad, line))
-            .for_each(|new_node| {
-                to_process.push_back((next.0 + 1, new_node));
-            });
-    }
-
-    panic!("input should have an answer")
+    search::bfs(
+        StateNode::start_state(total_dpads),
+        |node| {
+            [DPad::Up, DPad::Down, DPad::Left, DPad::Right, DPad::A]
+                .into_iter()
+                .flat_map(|human_dpad| node.handle_dpad_press(human_dpad, line))
+                .collect::<Vec<_>>()
+        },
+        |node| node.is_end_state(line),
+        Predecessors::First,
+    )
+    .cost()
+    .expect("input should have an answer")
 }
 
 fn get_numeric(line: &str) -> usize {
@@ -243,18 +197,35 @@ fn get_numeric(line: &str) -> usize {
     line[0..3].parse::<usize>().expect("input should be xxxA")
 }
 
-fn p1(input: &str) -> String {
-    //could also use the more efficient `solve(input, 2)`
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
     input
         .trim()
         .lines()
-        .map(|line| line.trim())
+        .map(|line| {
+            let line = line.trim();
+            let valid = line.len() == 4
+                && line.ends_with('A')
+                && line[0..3].chars().all(|ch| ch.is_ascii_digit());
+
+            if valid {
+                Ok(line.to_string())
+            } else {
+                Err(ParseError::new(line, "expected a code like 029A"))
+            }
+        })
+        .collect()
+}
+
+fn p1(codes: &[String]) -> String {
+    //could also use the more efficient `solve(codes, 2)`
+    codes
+        .iter()
         .map(|line| find_shortest(line, 2) * get_numeric(line))
         .sum::<usize>()
         .to_string()
 }
 
-fn solve(input: &str, max_depth: usize) -> String {
+fn solve(codes: &[String], max_depth: usize) -> String {
     // solution from: https://www.reddit.com/r/adventofcode/comments/1hjx0x4/2024_day_21_quick_tutorial_to_solve_part_2_in/
     // ^ without this, was difficult to solve
 
@@ -264,43 +235,31 @@ fn solve(input: &str, max_depth: usize) -> String {
         start: char,
         end: char,
     ) -> Vec<Vec<char>> {
-        let end_pos = rev_graph.get(&end).expect("valid node");
-
-        let mut result = vec![];
-
-        let mut visited = HashSet::new();
-        let mut current_level = vec![(*rev_graph.get(&start).expect("valid node"), vec![])];
-
-        while result.is_empty() {
-            let mut next_level = vec![];
+        let end_pos = *rev_graph.get(&end).expect("valid node");
 
-            current_level.into_iter().for_each(|current_node| {
-                if current_node.0 == *end_pos {
-                    result.push(current_node.1);
-                } else {
-                    visited.insert(current_node.0);
-
-                    [
-                        ((current_node.0 .0 - 1, current_node.0 .1), '<'),
-                        ((current_node.0 .0 + 1, current_node.0 .1), '>'),
-                        ((current_node.0 .0, current_node.0 .1 - 1), '^'),
-                        ((current_node.0 .0, current_node.0 .1 + 1), 'v'),
-                    ]
+        search::bfs(
+            *rev_graph.get(&start).expect("valid node"),
+            |pos| {
+                let pos = IVec2::from(*pos);
+                Direction::ALL
                     .into_iter()
-                    .filter(|neighbour| !visited.contains(&neighbour.0))
-                    .filter(|neighbour| graph.get(&neighbour.0).is_some())
-                    .for_each(|neighbour| {
-                        let mut path = current_node.1.clone();
-                        path.push(neighbour.1);
-                        next_level.push((neighbour.0, path));
-                    });
-                }
-            });
-
-            current_level = next_level;
-        }
-
-        result
+                    .map(move |direction| (pos + direction.delta()).into())
+                    .filter(|neighbour: &(i32, i32)| graph.contains_key(neighbour))
+            },
+            |pos| *pos == end_pos,
+            Predecessors::All,
+        )
+        .all_optimal_paths()
+        .into_iter()
+        .map(|path| {
+            path.windows(2)
+                .map(|step| {
+                    let delta = IVec2::from(step[1]) - IVec2::from(step[0]);
+                    Direction::try_from(delta).expect("adjacent keys").to_char()
+                })
+                .collect()
+        })
+        .collect()
     }
 
     let numpad_seqs = {
@@ -468,11 +427,10 @@ fn solve(input: &str, max_depth: usize) -> String {
 
     let mut shortest_seq_cache = HashMap::new();
 
-    input
-        .trim()
-        .lines()
+    codes
+        .iter()
         .fold(0, |acc, line| {
-            let line = line.trim().chars().collect::<Vec<_>>();
+            let line = line.chars().collect::<Vec<_>>();
 
             let min = build_seq(&line, 0, 'A', vec![], &numpad_seqs)
                 .iter()
@@ -491,18 +449,36 @@ fn solve(input: &str, max_depth: usize) -> String {
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    solve(input, 25)
+fn p2(codes: &[String]) -> String {
+    solve(codes, 25)
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"
 029A
@@ -514,21 +490,21 @@ mod tests {
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "126384");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "248684");
+        assert_eq!(part1(Day21, SAMPLE_INPUT), "126384");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "154115708116294");
+        assert_eq!(part2(Day21, SAMPLE_INPUT), "154115708116294");
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "307055584161760");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day21, "029A\n98A\n"),
+            "line 2, column 1: expected a code like 029A, found \"98A\""
+        );
     }
+
+    crate::answers::actual_tests!(Day21);
 }
@@ -1,7 +1,7 @@
 use ahash::{HashMap, HashMapExt};
 use itertools::Itertools;
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/22/input.txt");
+use crate::{parse, ParseError, Solution};
 
 fn next_secret(secret: u64) -> u64 {
     fn mix_prune(secret: u64, number: u64) -> u64 {
@@ -13,23 +13,23 @@ fn next_secret(secret: u64) -> u64 {
     mix_prune(secret, secret * 2048)
 }
 
-fn p1(input: &str) -> String {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.parse::<u64>().expect("a number"))
-        .map(|number| (0..2000).fold(number, |acc, _| next_secret(acc)))
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    input.trim().lines().map(parse::number).collect()
+}
+
+fn p1(secrets: &[u64]) -> String {
+    secrets
+        .iter()
+        .map(|number| (0..2000).fold(*number, |acc, _| next_secret(acc)))
         .sum::<u64>()
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.parse::<u64>().expect("a number"))
+fn p2(secrets: &[u64]) -> String {
+    secrets
+        .iter()
         .map(|number| {
-            (0..2000).fold(vec![number], |mut acc, _| {
+            (0..2000).fold(vec![*number], |mut acc, _| {
                 acc.push(next_secret(*acc.last().unwrap()));
                 acc
             })
@@ -67,14 +67,32 @@ fn p2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = Vec<u64>;
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     #[test]
     fn test_p1_sample() {
@@ -104,36 +122,42 @@ mod tests {
             });
 
         assert_eq!(
-            p1(r"
+            part1(
+                Day22,
+                r"
 1
 10
 100
 2024
-"),
+"
+            ),
             "37327623"
         );
     }
 
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "15006633487");
-    }
-
     #[test]
     fn test_p2_sample() {
         assert_eq!(
-            p2(r"
+            part2(
+                Day22,
+                r"
 1
 2
 3
 2024
-"),
+"
+            ),
             "23"
         );
     }
 
     #[test]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "1710");
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(Day22, "1\n10\nx\n"),
+            "line 3, column 1: expected a number, found \"x\""
+        );
     }
+
+    crate::answers::actual_tests!(Day22);
 }
@@ -2,48 +2,54 @@ use std::collections::BinaryHeap;
 
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2024/23/input.txt");
+use crate::{export::Graph, parse, ParseError, Solution};
 
-struct Input<'a> {
-    edges: Vec<(&'a str, &'a str)>,
-    graph: HashMap<&'a str, HashSet<&'a str>>,
+pub struct Input {
+    edges: Vec<(String, String)>,
+    graph: HashMap<String, HashSet<String>>,
 }
 
-impl<'a> Input<'a> {
-    fn parse(input: &'a str) -> Self {
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
         let edges = input
             .trim()
             .lines()
-            .map(|line| line.trim().split_once("-").expect("xx-xx"))
-            .collect::<Vec<_>>();
+            .map(|line| {
+                let (a, b) = parse::split_once(line.trim(), "-")?;
+                Ok((a.to_string(), b.to_string()))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
         let graph = edges.iter().fold(
             HashMap::new(),
-            |mut acc: HashMap<&str, HashSet<&str>>, edge| {
-                acc.entry(edge.0).or_default().insert(edge.1);
-                acc.entry(edge.1).or_default().insert(edge.0);
+            |mut acc: HashMap<String, HashSet<String>>, edge| {
+                acc.entry(edge.0.clone())
+                    .or_default()
+                    .insert(edge.1.clone());
+                acc.entry(edge.1.clone())
+                    .or_default()
+                    .insert(edge.0.clone());
                 acc
             },
         );
 
-        Self { edges, graph }
+        Ok(Self { edges, graph })
     }
 }
 
-fn p1(input: &str) -> String {
-    let input = Input::parse(input);
-    let edges = input.edges;
-    let graph = input.graph;
+fn p1(input: &Input) -> String {
+    let edges = &input.edges;
+    let graph = &input.graph;
 
     edges
         .iter()
         .fold(HashSet::new(), |mut acc, edge| {
             graph
-                .get(edge.0)
+                .get(&edge.0)
                 .expect("visited")
-                .intersection(graph.get(edge.1).expect("visited"))
+                .intersection(graph.get(&edge.1).expect("visited"))
                 .map(|third| {
-                    let mut nodes = [edge.0, edge.1, third];
+                    let mut nodes = [edge.0.as_str(), edge.1.as_str(), third.as_str()];
                     nodes.sort_unstable();
                     (nodes[0], nodes[1], nodes[2])
                 })
@@ -59,9 +65,8 @@ fn p1(input: &str) -> String {
         .to_string()
 }
 
-fn p2(input: &str) -> String {
-    let input = Input::parse(input);
-    let graph = input.graph;
+fn p2(input: &Input) -> String {
+    let graph = &input.graph;
 
     // problem is akin to the "Clique problem", which is NP-Complete
     // so aim is to reduce the brute-force necessary
@@ -70,10 +75,10 @@ fn p2(input: &str) -> String {
     graph
         .iter()
         .flat_map(|(node, neighbours)| {
-            let neighbours = neighbours.iter().collect::<Vec<_>>();
+            let neighbours = neighbours.iter().map(String::as_str).collect::<Vec<_>>();
             (0..(2usize.pow(neighbours.len() as u32)))
                 .map(move |mut choice| {
-                    let mut chosen = vec![*node];
+                    let mut chosen = vec![node.as_str()];
                     let mut idx = 0;
                     while choice > 0 {
                         if choice % 2 == 1 {
@@ -108,9 +113,8 @@ fn p2(input: &str) -> String {
 // But again, you can't reasonably know this until you have already solved
 // day 23.
 #[allow(dead_code)]
-fn p2_cheat(input: &str) -> String {
-    let input = Input::parse(input);
-    let graph = input.graph;
+fn p2_cheat(input: &Input) -> String {
+    let graph = &input.graph;
 
     graph
         .iter()
@@ -121,8 +125,9 @@ fn p2_cheat(input: &str) -> String {
                     let mut chosen = neighbours
                         .iter()
                         .filter(|node| *node != delete)
+                        .map(String::as_str)
                         .collect::<Vec<_>>();
-                    chosen.push(node);
+                    chosen.push(node.as_str());
                     chosen.sort_unstable();
                     chosen
                 })
@@ -136,20 +141,44 @@ fn p2_cheat(input: &str) -> String {
         })
         .next()
         .expect("assumption that input is specially constructed such that clique size = max outdegree. if not, then too bad")
-        .iter()
-        .map(|x| **x)
-        .collect::<Vec<_>>()
         .join(",")
 }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+
+    fn graph(&self, parsed: &Self::Parsed) -> Option<Graph> {
+        let mut graph = Graph::new(false);
+        parsed
+            .edges
+            .iter()
+            .for_each(|(a, b)| graph.add_edge(a, b, &[]));
+        Some(graph)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
 
     const SAMPLE_INPUT: &str = r"
 kh-tc
@@ -188,35 +217,40 @@ td-yn
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "7");
-    }
-
-    #[test]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "1046");
+        assert_eq!(part1(Day23, SAMPLE_INPUT), "7");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "co,de,ka,ta");
+        assert_eq!(part2(Day23, SAMPLE_INPUT), "co,de,ka,ta");
     }
 
     #[test]
-    #[ignore = "np-complete problem, took 19s on local"]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "de,id,ke,ls,po,sn,tf,tl,tm,uj,un,xw,yz");
+    fn test_p2_cheat_sample() {
+        assert_eq!(
+            p2_cheat(&Input::parse(SAMPLE_INPUT).unwrap()),
+            "co,de,ka,ta"
+        );
     }
 
     #[test]
-    fn test_p2_cheat_sample() {
-        assert_eq!(p2_cheat(SAMPLE_INPUT), "co,de,ka,ta");
+    fn test_p2_cheat_actual() {
+        for (profile, input, expected) in crate::answers::actual_answers(23, 2) {
+            let answer = p2_cheat(&Input::parse(&input).unwrap());
+            assert_eq!(answer, expected, "profile {}", profile);
+        }
     }
 
     #[test]
-    fn test_p2_cheat_actual() {
+    fn test_parse_error() {
         assert_eq!(
-            p2_cheat(ACTUAL_INPUT),
-            "de,id,ke,ls,po,sn,tf,tl,tm,uj,un,xw,yz"
+            parse_error(Day23, "kh-tc\nqp kh\n"),
+            "line 2, column 1: expected \"-\", found \"qp kh\""
         );
     }
+
+    crate::answers::actual_tests!(
+        Day23,
+        ignore_part2 = "np-complete problem, took 19s on local"
+    );
 }
@@ -0,0 +1,789 @@
+use std::fmt::Display;
+
+use ahash::{HashMap, HashMapExt};
+
+use crate::{export::Graph, parse, rng::XorShift, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Operator {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Gate {
+    inputs: [String; 2],
+    output: String,
+    operator: Operator,
+}
+
+#[derive(Clone)]
+pub struct Input {
+    wires: HashMap<String, usize>,
+    gates: Vec<Gate>,
+    circuit: Circuit,
+}
+
+impl Input {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let (wires, gates_text) = parse::split_sections(input.trim())?;
+
+        let wires = wires
+            .trim()
+            .lines()
+            .map(|line| {
+                let (wire, val) = parse::split_once(line, ": ")?;
+                // the inputs are read into a u64
+                if ['x', 'y']
+                    .into_iter()
+                    .any(|prefix| bit_of(wire, prefix).is_some_and(|bit| bit >= 64))
+                {
+                    return Err(ParseError::new(wire, "inputs have at most 64 bits"));
+                }
+                match val {
+                    "0" | "1" => Ok((wire.to_string(), parse::number(val)?)),
+                    _ => Err(ParseError::new(val, "expected 0 or 1")),
+                }
+            })
+            .collect::<Result<HashMap<_, _>, ParseError>>()?;
+
+        let gates = gates_text
+            .trim()
+            .lines()
+            .map(|line| {
+                let (gate, output) = parse::split_once(line, " -> ")?;
+                let [left, operator, right] = gate.split(" ").collect::<Vec<_>>()[..] else {
+                    return Err(ParseError::new(gate, "expected a gate like x00 AND y00"));
+                };
+
+                Ok(Gate {
+                    inputs: [left.to_string(), right.to_string()],
+                    output: output.to_string(),
+                    operator: match operator {
+                        "AND" => Operator::And,
+                        "OR" => Operator::Or,
+                        "XOR" => Operator::Xor,
+                        _ => return Err(ParseError::new(operator, "unknown operator")),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let circuit = Circuit::compile(&gates).map_err(|err| {
+            // point at the first gate using the wire, or the second one driving it
+            let (wire, skip) = match &err {
+                CircuitError::Cycle(wires) => (&wires[0], 0),
+                CircuitError::Undriven(wire) => (wire, 0),
+                CircuitError::MultipleDrivers(wire) => (wire, 1),
+            };
+            let line = gates_text
+                .lines()
+                .filter(|line| line.split(' ').any(|token| token == wire))
+                .nth(skip)
+                .unwrap_or(gates_text);
+            ParseError::new(line, err.to_string())
+        })?;
+
+        Ok(Self {
+            wires,
+            gates,
+            circuit,
+        })
+    }
+
+    /// The number made of the initial values of the wires starting with `prefix`.
+    fn number(&self, prefix: char) -> u64 {
+        self.wires
+            .iter()
+            .filter_map(|(wire, value)| Some((bit_of(wire, prefix)?, *value as u64)))
+            .fold(0, |acc, (bit, value)| acc | (value << bit))
+    }
+
+    pub fn circuit(&self) -> &Circuit {
+        &self.circuit
+    }
+}
+
+/// The bit of a wire like `x05`, if the wire starts with `prefix`.
+fn bit_of(wire: &str, prefix: char) -> Option<usize> {
+    wire.strip_prefix(prefix)?.parse().ok()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The outputs of the gates that cannot be evaluated, because they depend on a loop.
+    Cycle(Vec<String>),
+    /// A wire that is read by a gate, but is neither an input nor driven by a gate.
+    Undriven(String),
+    /// A wire that is driven by more than one gate.
+    MultipleDrivers(String),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::Cycle(wires) => write!(f, "gates form a cycle: {}", wires.join(", ")),
+            CircuitError::Undriven(wire) => write!(f, "wire {} is never driven", wire),
+            CircuitError::MultipleDrivers(wire) => {
+                write!(f, "wire {} is driven by more than one gate", wire)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// A gate of a compiled [`Circuit`], with wires as indices.
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    inputs: [usize; 2],
+    output: usize,
+    operator: Operator,
+}
+
+/// The gates compiled into an evaluation plan, where every gate comes after the gates driving its
+/// inputs. The inputs are the `xNN` and `yNN` wires, and the output is made of the `zNN` wires.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    names: Vec<String>,
+    plan: Vec<Step>,
+    x: Vec<(usize, usize)>,
+    y: Vec<(usize, usize)>,
+    z: Vec<(usize, usize)>,
+}
+
+impl Circuit {
+    fn compile(gates: &[Gate]) -> Result<Self, CircuitError> {
+        let mut names = vec![];
+        let mut ids = HashMap::new();
+        let mut id = |wire: &str| {
+            *ids.entry(wire.to_string()).or_insert_with(|| {
+                names.push(wire.to_string());
+                names.len() - 1
+            })
+        };
+        let steps = gates
+            .iter()
+            .map(|gate| Step {
+                inputs: [id(&gate.inputs[0]), id(&gate.inputs[1])],
+                output: id(&gate.output),
+                operator: gate.operator,
+            })
+            .collect::<Vec<_>>();
+
+        let mut driver = vec![None; names.len()];
+        for (idx, step) in steps.iter().enumerate() {
+            if driver[step.output].replace(idx).is_some() {
+                return Err(CircuitError::MultipleDrivers(names[step.output].clone()));
+            }
+        }
+
+        let is_input = |wire: usize| bit_of(&names[wire], 'x').or(bit_of(&names[wire], 'y'));
+        if let Some(wire) = steps
+            .iter()
+            .flat_map(|step| step.inputs)
+            .find(|wire| driver[*wire].is_none() && is_input(*wire).is_none())
+        {
+            return Err(CircuitError::Undriven(names[wire].clone()));
+        }
+
+        // Kahn's algorithm, where a gate is ready once all its driven inputs are known
+        let mut readers = vec![vec![]; names.len()];
+        let mut pending = steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                step.inputs
+                    .iter()
+                    .filter(|wire| driver[**wire].is_some())
+                    .map(|wire| readers[*wire].push(idx))
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        let mut ready = (0..steps.len())
+            .filter(|idx| pending[*idx] == 0)
+            .collect::<Vec<_>>();
+        let mut plan = vec![];
+
+        while let Some(idx) = ready.pop() {
+            plan.push(steps[idx]);
+            readers[steps[idx].output].iter().for_each(|reader| {
+                pending[*reader] -= 1;
+                if pending[*reader] == 0 {
+                    ready.push(*reader);
+                }
+            });
+        }
+
+        if plan.len() < steps.len() {
+            let mut wires = steps
+                .iter()
+                .zip(&pending)
+                .filter(|(_, pending)| **pending > 0)
+                .map(|(step, _)| names[step.output].clone())
+                .collect::<Vec<_>>();
+            wires.sort_unstable();
+            return Err(CircuitError::Cycle(wires));
+        }
+
+        let bits = |prefix| {
+            let mut bits = names
+                .iter()
+                .enumerate()
+                .filter_map(|(wire, name)| Some((bit_of(name, prefix)?, wire)))
+                .collect::<Vec<_>>();
+            bits.sort_unstable();
+            bits
+        };
+
+        Ok(Self {
+            x: bits('x'),
+            y: bits('y'),
+            z: bits('z'),
+            names,
+            plan,
+        })
+    }
+
+    /// Number of bits of the output.
+    pub fn output_bits(&self) -> usize {
+        self.z.len()
+    }
+
+    /// Evaluates the circuit with the `xNN` wires set to the bits of `x`, and the `yNN` wires to
+    /// the bits of `y`. Wires past bit 63 of the inputs are 0, and outputs past bit 127 are dropped.
+    pub fn evaluate(&self, x: u64, y: u64) -> u128 {
+        let is_set = |value: u64, bit: usize| {
+            value
+                .checked_shr(bit as u32)
+                .is_some_and(|shifted| shifted & 1 == 1)
+        };
+        let mut values = vec![false; self.names.len()];
+        self.x
+            .iter()
+            .for_each(|(bit, wire)| values[*wire] = is_set(x, *bit));
+        self.y
+            .iter()
+            .for_each(|(bit, wire)| values[*wire] = is_set(y, *bit));
+
+        self.plan.iter().for_each(|step| {
+            let [a, b] = step.inputs.map(|wire| values[wire]);
+            values[step.output] = match step.operator {
+                Operator::And => a & b,
+                Operator::Or => a | b,
+                Operator::Xor => a ^ b,
+            };
+        });
+
+        self.z.iter().fold(0, |acc, (bit, wire)| {
+            acc | (values[*wire] as u128)
+                .checked_shl(*bit as u32)
+                .unwrap_or(0)
+        })
+    }
+
+    /// The lowest output bit where the circuit does not compute `x + y`, if any.
+    pub fn first_wrong_bit(&self, x: u64, y: u64) -> Option<usize> {
+        let wrong = (self.evaluate(x, y) ^ (x as u128 + y as u128)) & mask(self.output_bits());
+        (wrong != 0).then(|| wrong.trailing_zeros() as usize)
+    }
+
+    /// Checks the circuit as an adder on `samples` pseudo random inputs (and a few edge cases),
+    /// and returns the lowest output bit that is ever wrong. The inputs are the same on every call.
+    pub fn fuzz_adder(&self, samples: usize) -> Option<usize> {
+        let input_mask = mask(self.x.len()) as u64;
+        let mut rng = XorShift::new(0x2024);
+        let mut next = move || rng.next_u64() & input_mask;
+
+        [(0, 0), (input_mask, 1), (input_mask, input_mask)]
+            .into_iter()
+            .chain((0..samples).map(|_| (next(), next())))
+            .filter_map(|(x, y)| self.first_wrong_bit(x, y))
+            .min()
+    }
+}
+
+/// The lowest `bits` bits set, all of them from 128 bits.
+fn mask(bits: usize) -> u128 {
+    1u128
+        .checked_shl(bits as u32)
+        .map_or(u128::MAX, |bit| bit - 1)
+}
+
+fn p1(input: &Input) -> String {
+    input
+        .circuit
+        .evaluate(input.number('x'), input.number('y'))
+        .to_string()
+}
+
+fn get_z(number: usize) -> String {
+    format!("z{}{}", if number > 9 { "" } else { "0" }, number)
+}
+
+fn get_y(number: usize) -> String {
+    format!("y{}{}", if number > 9 { "" } else { "0" }, number)
+}
+
+fn get_x(number: usize) -> String {
+    format!("x{}{}", if number > 9 { "" } else { "0" }, number)
+}
+
+// the circuit should be a ripple carry adder, where for every bit n:
+//
+// x_n ^ y_n = insum_n
+// carry_{n-1} ^ insum_n = sum_n (zNN)
+// x_n & y_n = subcarry_n
+// carry_{n-1} & insum_n = supercarry_n
+// subcarry_n | supercarry_n = carry_n
+//
+// bit 0 has no carry in, so sum_0 = insum_0 and carry_0 = subcarry_0, and the last carry is the
+// extra z bit at the top.
+//
+// the adder is checked bit by bit from the lowest one, where the carry coming in has already been
+// checked. a wire that does not fit the equations is swapped with the one that does, and the bit
+// is checked again.
+struct AdderRepair {
+    gates: Vec<Gate>,
+    swaps: Vec<(String, String)>,
+}
+
+impl AdderRepair {
+    fn find(&self, operator: Operator, a: &str, b: &str) -> Option<&Gate> {
+        self.gates.iter().find(|gate| {
+            gate.operator == operator
+                && ((gate.inputs[0] == a && gate.inputs[1] == b)
+                    || (gate.inputs[0] == b && gate.inputs[1] == a))
+        })
+    }
+
+    /// The other input of the gate that has `wire` as one of its inputs.
+    fn other_input(&self, operator: Operator, wire: &str) -> Option<String> {
+        self.gates
+            .iter()
+            .filter(|gate| gate.operator == operator)
+            .find_map(|gate| {
+                if gate.inputs[0] == wire {
+                    Some(gate.inputs[1].clone())
+                } else if gate.inputs[1] == wire {
+                    Some(gate.inputs[0].clone())
+                } else {
+                    None
+                }
+            })
+    }
+
+    fn output(&self, operator: Operator, a: &str, b: &str) -> Option<String> {
+        self.find(operator, a, b).map(|gate| gate.output.clone())
+    }
+
+    fn swap(&mut self, a: String, b: String) {
+        self.gates.iter_mut().for_each(|gate| {
+            if gate.output == a {
+                gate.output = b.clone();
+            } else if gate.output == b {
+                gate.output = a.clone();
+            }
+        });
+        self.swaps.push((a, b));
+    }
+
+    /// Checks bit `n` given its carry in, and returns its carry out. Returns `Err` with the pair of
+    /// wires to swap if the bit does not fit the equations.
+    fn check_bit(&self, n: usize, carry: Option<&str>) -> Result<String, Option<(String, String)>> {
+        let insum = self
+            .output(Operator::Xor, &get_x(n), &get_y(n))
+            .ok_or(None)?;
+        let subcarry = self
+            .output(Operator::And, &get_x(n), &get_y(n))
+            .ok_or(None)?;
+
+        let Some(carry) = carry else {
+            return if insum == get_z(n) {
+                Ok(subcarry)
+            } else {
+                Err(Some((insum, get_z(n))))
+            };
+        };
+
+        let Some(sum) = self.output(Operator::Xor, carry, &insum) else {
+            // one of the inputs of the sum gate is wrong, found from the gate having the other one
+            return Err(match self.other_input(Operator::Xor, carry) {
+                Some(other) => Some((insum, other)),
+                None => self
+                    .other_input(Operator::Xor, &insum)
+                    .map(|other| (carry.to_string(), other)),
+            });
+        };
+        if sum != get_z(n) {
+            return Err(Some((sum, get_z(n))));
+        }
+
+        let supercarry = self.output(Operator::And, carry, &insum).ok_or(None)?;
+        self.output(Operator::Or, &subcarry, &supercarry)
+            .ok_or_else(|| match self.other_input(Operator::Or, &subcarry) {
+                Some(other) => Some((supercarry.clone(), other)),
+                None => self
+                    .other_input(Operator::Or, &supercarry)
+                    .map(|other| (subcarry.clone(), other)),
+            })
+    }
+
+    /// Returns the swapped pairs of wires, or `None` if the circuit cannot be repaired into an
+    /// adder of `bits` bits.
+    fn repair(gates: Vec<Gate>, bits: usize) -> Option<Vec<(String, String)>> {
+        let mut repair = Self {
+            gates,
+            swaps: vec![],
+        };
+        let mut carry = None;
+        let mut n = 0;
+
+        while n < bits {
+            match repair.check_bit(n, carry.as_deref()) {
+                Ok(carry_out) => {
+                    carry = Some(carry_out);
+                    n += 1;
+                }
+                // every swap fixes a wire for good, so more swaps than bits means it is stuck
+                Err(Some((a, b))) if repair.swaps.len() < bits => repair.swap(a, b),
+                Err(_) => return None,
+            }
+        }
+
+        let carry = carry?;
+        if carry != get_z(bits) {
+            repair.swap(carry, get_z(bits));
+        }
+
+        // the equations are only checked structurally, so make sure that it really adds
+        let circuit = Circuit::compile(&repair.gates).ok()?;
+        circuit.fuzz_adder(100).is_none().then_some(repair.swaps)
+    }
+}
+
+fn p2(input: &Input) -> String {
+    let bits = input
+        .wires
+        .keys()
+        .filter(|wire| wire.starts_with('x'))
+        .count();
+    let Some(swaps) = AdderRepair::repair(input.gates.clone(), bits) else {
+        return "not a repairable adder".to_string();
+    };
+
+    let mut wires = swaps
+        .into_iter()
+        .flat_map(|(a, b)| [a, b])
+        .collect::<Vec<_>>();
+    wires.sort_unstable();
+    wires.join(",")
+}
+
+/// The circuit with the wires of each bit grouped together, the inputs in green and every gate
+/// output coloured by its operator: AND red, OR yellow, XOR blue.
+fn graph(input: &Input) -> Graph {
+    let mut graph = Graph::new(true);
+    let color = |operator| match operator {
+        Operator::And => "red",
+        Operator::Or => "yellow",
+        Operator::Xor => "blue",
+    };
+    let style = |color| [("style", "filled"), ("fillcolor", color)];
+
+    let mut inputs = input.wires.keys().collect::<Vec<_>>();
+    inputs.sort_unstable();
+    inputs.into_iter().for_each(|wire| {
+        let group = wire.get(1..).map(|bit| format!("bit {}", bit));
+        graph.add_node(wire, group.as_deref(), &style("green"));
+    });
+
+    input.gates.iter().for_each(|gate| {
+        let group = gate
+            .output
+            .strip_prefix('z')
+            .map(|bit| format!("bit {}", bit));
+        graph.add_node(&gate.output, group.as_deref(), &style(color(gate.operator)));
+        gate.inputs
+            .iter()
+            .for_each(|wire| graph.add_edge(wire, &gate.output, &[]));
+    });
+
+    graph
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Input;
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+
+    fn graph(&self, parsed: &Self::Parsed) -> Option<Graph> {
+        Some(graph(parsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{part1, part2};
+
+    const SAMPLE_INPUT_1: &str = r"
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    const SAMPLE_INPUT_2: &str = r"
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day24, SAMPLE_INPUT_1), "4");
+        assert_eq!(part1(Day24, SAMPLE_INPUT_2), "2024");
+    }
+
+    #[test]
+    fn test_p2_not_an_adder() {
+        assert_eq!(part2(Day24, SAMPLE_INPUT_1), "not a repairable adder");
+        assert_eq!(part2(Day24, SAMPLE_INPUT_2), "not a repairable adder");
+        // a half adder without its carry
+        assert_eq!(
+            part2(Day24, "x00: 1\ny00: 0\n\nx00 XOR y00 -> z00"),
+            "not a repairable adder"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Input::parse_input("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00")
+            .err()
+            .unwrap();
+        assert_eq!(err.text, "NAND");
+        let err = Input::parse_input("x00: 2\n\nx00 AND x00 -> z00")
+            .err()
+            .unwrap();
+        assert_eq!(err.text, "2");
+    }
+
+    /// A ripple carry adder of `bits` bits, where the outputs of each pair in `swaps` are swapped.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let swapped = |wire: String| {
+            swaps
+                .iter()
+                .find_map(|(a, b)| {
+                    if *a == wire {
+                        Some(b.to_string())
+                    } else if *b == wire {
+                        Some(a.to_string())
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(wire)
+        };
+
+        let mut lines = (0..bits)
+            .flat_map(|n| [format!("x{:02}: 0", n), format!("y{:02}: 0", n)])
+            .collect::<Vec<_>>();
+        lines.push(String::new());
+
+        (0..bits).for_each(|n| {
+            let (x, y) = (get_x(n), get_y(n));
+            let mut gates = vec![
+                (format!("{} XOR {}", x, y), format!("i{:02}", n)),
+                (format!("{} AND {}", x, y), format!("s{:02}", n)),
+            ];
+            if n == 0 {
+                gates[0].1 = get_z(0);
+            } else {
+                let carry = if n == 1 {
+                    "s00".to_string()
+                } else {
+                    format!("c{:02}", n - 1)
+                };
+                let carry_out = if n == bits - 1 {
+                    get_z(bits)
+                } else {
+                    format!("c{:02}", n)
+                };
+                gates.extend([
+                    (format!("{} XOR i{:02}", carry, n), get_z(n)),
+                    (format!("{} AND i{:02}", carry, n), format!("p{:02}", n)),
+                    (format!("s{:02} OR p{:02}", n, n), carry_out),
+                ]);
+            }
+            gates.into_iter().for_each(|(gate, output)| {
+                lines.push(format!("{} -> {}", gate, swapped(output)));
+            });
+        });
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_circuit() {
+        let circuit = Input::parse_input(&adder(10, &[])).unwrap().circuit;
+        assert_eq!(circuit.output_bits(), 11);
+        assert_eq!(circuit.evaluate(1000, 23), 1023);
+        assert_eq!(circuit.evaluate(1023, 1023), 2046);
+        assert_eq!(circuit.fuzz_adder(100), None);
+
+        let circuit = Input::parse_input(&adder(10, &[("z03", "p03"), ("z07", "c07")]))
+            .unwrap()
+            .circuit;
+        assert_eq!(circuit.first_wrong_bit(0b1000, 0), Some(3));
+        assert_eq!(circuit.fuzz_adder(100), Some(3));
+    }
+
+    #[test]
+    fn test_circuit_64_bits() {
+        let circuit = Input::parse_input(&adder(64, &[])).unwrap().circuit;
+        assert_eq!(circuit.output_bits(), 65);
+        assert_eq!(circuit.evaluate(u64::MAX, u64::MAX), u64::MAX as u128 * 2);
+        assert_eq!(circuit.first_wrong_bit(u64::MAX, 1), None);
+        assert_eq!(circuit.fuzz_adder(100), None);
+        assert_eq!(mask(0), 0);
+        assert_eq!(mask(65), (1 << 65) - 1);
+        assert_eq!(mask(128), u128::MAX);
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = Day24
+            .graph(&Input::parse_input(SAMPLE_INPUT_1).unwrap())
+            .unwrap();
+        assert_eq!(graph.nodes.len(), 9);
+        assert_eq!(graph.edges.len(), 6);
+
+        let z01 = graph.nodes.iter().find(|node| node.id == "z01").unwrap();
+        assert_eq!(z01.group.as_deref(), Some("bit 01"));
+        assert_eq!(z01.attributes["fillcolor"], "blue");
+    }
+
+    #[test]
+    fn test_circuit_errors() {
+        let circuit = |gates: &str| {
+            Input::parse_input(&format!("x00: 1\ny00: 0\n\n{}", gates))
+                .err()
+                .map(|err| (err.text, err.message))
+        };
+
+        assert_eq!(
+            circuit("x00 AND b -> a\na OR y00 -> b\na XOR b -> z00"),
+            Some((
+                "x00 AND b -> a".to_string(),
+                "gates form a cycle: a, b, z00".to_string()
+            ))
+        );
+        assert_eq!(
+            circuit("x00 AND q00 -> z00"),
+            Some((
+                "x00 AND q00 -> z00".to_string(),
+                "wire q00 is never driven".to_string()
+            ))
+        );
+        assert_eq!(
+            circuit("x00 AND y00 -> z00\nx00 OR y00 -> z00"),
+            Some((
+                "x00 OR y00 -> z00".to_string(),
+                "wire z00 is driven by more than one gate".to_string()
+            ))
+        );
+        assert_eq!(
+            Input::parse_input("x64: 1\ny00: 0\n\nx64 AND y00 -> z00")
+                .err()
+                .map(|err| (err.text, err.message)),
+            Some(("x64".to_string(), "inputs have at most 64 bits".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_p2_repair() {
+        let input = adder(10, &[]);
+        assert_eq!(part2(Day24, &input), "");
+
+        let input = adder(
+            10,
+            &[
+                ("z03", "p03"),
+                ("i05", "s05"),
+                ("z07", "c07"),
+                ("z09", "s09"),
+            ],
+        );
+        assert_eq!(part2(Day24, &input), "c07,i05,p03,s05,s09,z03,z07,z09");
+    }
+
+    crate::answers::actual_tests!(Day24);
+}
@@ -0,0 +1,180 @@
+use crate::{parse, ParseError, Solution};
+
+const BOUNDS_WIDTH: usize = 5;
+const BOUNDS_HEIGHT: usize = 7;
+
+#[derive(Debug)]
+pub struct Key {
+    heights: [u64; BOUNDS_WIDTH],
+}
+
+#[derive(Debug)]
+pub struct Lock {
+    heights: [u64; BOUNDS_WIDTH],
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Key>, Vec<Lock>), ParseError> {
+    input
+        .trim()
+        .split("\n\n")
+        .try_fold((vec![], vec![]), |(mut keys, mut locks), section| {
+            let section = section.trim().lines().collect::<Vec<_>>();
+
+            if section.len() != BOUNDS_HEIGHT {
+                return Err(ParseError::new(
+                    section.last().copied().unwrap_or_default(),
+                    format!("expected a schematic of {} lines", BOUNDS_HEIGHT),
+                ));
+            }
+            for line in section.iter() {
+                if line.len() != BOUNDS_WIDTH {
+                    return Err(ParseError::new(
+                        line,
+                        format!("expected a line of {} characters", BOUNDS_WIDTH),
+                    ));
+                }
+                if let Some((_, _, text)) =
+                    parse::row_chars(line).find(|(_, ch, _)| *ch != '#' && *ch != '.')
+                {
+                    return Err(parse::unexpected_char(text));
+                }
+            }
+
+            let heights = (0..BOUNDS_WIDTH)
+                .map(|col| {
+                    section
+                        .iter()
+                        .filter(|line| line.as_bytes()[col] == b'#')
+                        .count() as u64
+                })
+                .collect::<Vec<_>>();
+
+            if section[0] == "#####" {
+                locks.push(Lock {
+                    //heights: heights.into(),
+                    heights: heights.as_slice().try_into().expect("length BOUNDS_WIDTH"),
+                });
+            } else {
+                keys.push(Key {
+                    heights: heights.as_slice().try_into().expect("length BOUNDS_WIDTH"),
+                });
+            }
+
+            Ok((keys, locks))
+        })
+}
+
+fn p1((keys, locks): &(Vec<Key>, Vec<Lock>)) -> String {
+    keys.iter()
+        .map(|key| {
+            locks
+                .iter()
+                .filter(|lock| {
+                    key.heights
+                        .iter()
+                        .zip(lock.heights)
+                        .map(|(l, k)| l + k)
+                        .all(|h| h <= BOUNDS_HEIGHT as u64)
+                })
+                .count()
+        })
+        .sum::<usize>()
+        .to_string()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = (Vec<Key>, Vec<Lock>);
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> String {
+        // day 25 only has one puzzle
+        String::new()
+    }
+
+    fn has_part2(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1};
+
+    const SAMPLE_INPUT: &str = r"
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(Day25, SAMPLE_INPUT), "3");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(
+                Day25,
+                "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n#####\n##x##\n"
+            ),
+            "line 10, column 1: expected a schematic of 7 lines, found \"##x##\""
+        );
+        assert_eq!(
+            parse_error(Day25, "#####\n.####\n.####\n.##x#\n.#.#.\n.#...\n.....\n"),
+            "line 4, column 4: unexpected character, found \"x\""
+        );
+    }
+
+    crate::answers::actual_tests!(Day25, part1_only);
+}
@@ -0,0 +1,197 @@
+//! The decimal digits of numbers, for the puzzles that cut numbers apart or glue them together.
+//! `0` has a single digit, as it is written.
+
+/// The number of digits of `value`.
+pub fn count(value: u64) -> u32 {
+    value.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// Splits `value` into the numbers written by its left and right halves, or `None` if it has an
+/// odd number of digits. Leading zeros of the right half are dropped, so `1000` is `(10, 0)`.
+pub fn split_half(value: u64) -> Option<(u64, u64)> {
+    let digit_count = count(value);
+    digit_count.is_multiple_of(2).then(|| {
+        let half = 10u64.pow(digit_count / 2);
+        (value / half, value % half)
+    })
+}
+
+/// The number written by the digits of `left` followed by the ones of `right`, or `None` if it
+/// does not fit in a `u64`.
+pub fn checked_concat(left: u64, right: u64) -> Option<u64> {
+    // a leading 0 is dropped, and 10^20 for a `right` of 20 digits does not fit
+    if left == 0 {
+        return Some(right);
+    }
+    left.checked_mul(10u64.checked_pow(count(right))?)?
+        .checked_add(right)
+}
+
+/// Like [`checked_concat`], but panics if the result does not fit in a `u64`.
+pub fn concat(left: u64, right: u64) -> u64 {
+    checked_concat(left, right).expect("concatenation overflows u64")
+}
+
+/// The number written by the digits of `value` in reverse, or `None` if it does not fit in a
+/// `u64`. Trailing zeros become leading ones and are dropped, so `120` is `21`.
+pub fn checked_reverse(value: u64) -> Option<u64> {
+    digits(value).rev().try_fold(0u64, |acc, digit| {
+        acc.checked_mul(10)?.checked_add(digit as u64)
+    })
+}
+
+/// Like [`checked_reverse`], but panics if the result does not fit in a `u64`.
+pub fn reverse(value: u64) -> u64 {
+    checked_reverse(value).expect("reversal overflows u64")
+}
+
+/// The digits of `value`, most significant first.
+pub fn digits(value: u64) -> Digits {
+    Digits {
+        value,
+        low: 0,
+        high: count(value),
+    }
+}
+
+/// The iterator of [`digits`].
+#[derive(Debug, Clone)]
+pub struct Digits {
+    value: u64,
+    /// The positions of the digits left to yield, counted from the least significant one.
+    low: u32,
+    high: u32,
+}
+
+impl Digits {
+    fn at(&self, position: u32) -> u8 {
+        (self.value / 10u64.pow(position) % 10) as u8
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        (self.low < self.high).then(|| {
+            self.high -= 1;
+            self.at(self.high)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.high - self.low) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    fn next_back(&mut self) -> Option<u8> {
+        (self.low < self.high).then(|| {
+            self.low += 1;
+            self.at(self.low - 1)
+        })
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+
+    /// The edge cases around powers of ten and `u64::MAX`, and random numbers of every length.
+    fn samples() -> Vec<u64> {
+        let mut rng = XorShift::new(0x2024_0711);
+        let random = (0..2000).map(|idx| rng.next_u64() >> (idx % 64));
+
+        (0..=19)
+            .flat_map(|exp| {
+                let power = 10u64.pow(exp);
+                [power - 1, power, power + 1]
+            })
+            .chain([0, u64::MAX, u64::MAX - 1, u64::MAX / 10])
+            .chain(random)
+            .collect()
+    }
+
+    #[test]
+    fn test_count_and_digits() {
+        for value in samples() {
+            let text = value.to_string();
+            assert_eq!(count(value) as usize, text.len(), "{}", value);
+
+            let expected = text.bytes().map(|byte| byte - b'0').collect::<Vec<_>>();
+            assert_eq!(digits(value).collect::<Vec<_>>(), expected);
+            assert_eq!(digits(value).len(), expected.len());
+            assert!(digits(value).rev().eq(expected.iter().rev().copied()));
+        }
+        assert_eq!(count(u64::MAX), 20);
+
+        let mut both_ends = digits(12345);
+        assert_eq!(both_ends.next(), Some(1));
+        assert_eq!(both_ends.next_back(), Some(5));
+        assert_eq!(both_ends.collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_split_half() {
+        for value in samples() {
+            let text = value.to_string();
+            let expected = text.len().is_multiple_of(2).then(|| {
+                let (left, right) = text.split_at(text.len() / 2);
+                (left.parse().unwrap(), right.parse().unwrap())
+            });
+            assert_eq!(split_half(value), expected, "{}", value);
+        }
+        assert_eq!(split_half(1000), Some((10, 0)));
+        assert_eq!(split_half(u64::MAX), Some((1844674407, 3709551615)));
+    }
+
+    #[test]
+    fn test_concat() {
+        let samples = samples();
+        for (left, right) in samples.iter().zip(samples.iter().rev()) {
+            for (left, right) in [
+                (*left, *right),
+                (0, *right),
+                (*left % 1000, *right),
+                (*left, *right % 1000),
+            ] {
+                let expected = format!("{}{}", left, right).parse::<u64>().ok();
+                assert_eq!(checked_concat(left, right), expected, "{} {}", left, right);
+            }
+        }
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(0, 7), 7);
+        assert_eq!(checked_concat(1, u64::MAX), None);
+        assert_eq!(checked_concat(0, u64::MAX), Some(u64::MAX));
+        assert_eq!(checked_concat(0, 0), Some(0));
+        assert_eq!(checked_concat(1844674407, 3709551615), Some(u64::MAX));
+        assert_eq!(checked_concat(1844674407, 3709551616), None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        for value in samples() {
+            let expected = value
+                .to_string()
+                .chars()
+                .rev()
+                .collect::<String>()
+                .parse::<u64>()
+                .ok();
+            assert_eq!(checked_reverse(value), expected, "{}", value);
+            if let Some(reversed) = expected.filter(|_| value % 10 != 0) {
+                assert_eq!(reverse(reversed), value);
+            }
+        }
+        assert_eq!(reverse(120), 21);
+        assert_eq!(checked_reverse(u64::MAX), None);
+        assert_eq!(
+            checked_reverse(9_000_000_000_000_000_001),
+            Some(1_000_000_000_000_000_009)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! The four cardinal directions on a [`Grid`](crate::grid::Grid), where up is towards `y = 0`.
+
+use glam::IVec2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses the arrows `^`, `v`, `<` and `>`.
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub const fn delta(self) -> IVec2 {
+        match self {
+            Direction::Up => IVec2::new(0, -1),
+            Direction::Right => IVec2::new(1, 0),
+            Direction::Down => IVec2::new(0, 1),
+            Direction::Left => IVec2::new(-1, 0),
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The least number of 90 degree turns to face `other`, either 0, 1 or 2.
+    pub fn rotations_between(self, other: Self) -> u32 {
+        let clockwise = (other as u32 + 4 - self as u32) % 4;
+        clockwise.min(4 - clockwise)
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(direction: Direction) -> Self {
+        direction.delta()
+    }
+}
+
+impl TryFrom<IVec2> for Direction {
+    /// The vector is not a unit step in a cardinal direction.
+    type Error = IVec2;
+
+    fn try_from(delta: IVec2) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+            .ok_or(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        Direction::ALL.into_iter().for_each(|direction| {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.delta() + direction.reverse().delta(), IVec2::ZERO);
+            assert_eq!(Direction::try_from(direction.delta()), Ok(direction));
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+        });
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::try_from(IVec2::new(1, 1)), Err(IVec2::new(1, 1)));
+    }
+
+    #[test]
+    fn test_rotations_between() {
+        assert_eq!(Direction::Up.rotations_between(Direction::Up), 0);
+        assert_eq!(Direction::Up.rotations_between(Direction::Left), 1);
+        assert_eq!(Direction::Left.rotations_between(Direction::Up), 1);
+        assert_eq!(Direction::Right.rotations_between(Direction::Left), 2);
+    }
+}
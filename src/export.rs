@@ -0,0 +1,397 @@
+//! Export of a day's graph (e.g. the circuit of day 24) for viewing in other tools, with
+//! `aoc run --day NN --export <PATH>`.
+//!
+//! A [`Graph`] is a list of nodes and edges, each with free-form attributes. The attributes are
+//! written as is to DOT and JSON. Mermaid has no attributes, so only `label` (the text of a node)
+//! and `fillcolor` (its background) are kept there.
+
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+impl Format {
+    /// Picks the format from the extension of `path`: `.dot` or `.gv`, `.mmd` or `.mermaid`, and
+    /// `.json`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "dot" | "gv" => Some(Format::Dot),
+            "mmd" | "mermaid" => Some(Format::Mermaid),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub id: String,
+    /// Nodes of the same group are drawn together.
+    pub group: Option<String>,
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    pub directed: bool,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+fn attributes(attributes: &[(&str, &str)]) -> BTreeMap<String, String> {
+    attributes
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+impl Graph {
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+
+    /// Adds a node. Nodes that are only used by edges do not need to be added.
+    pub fn add_node(&mut self, id: &str, group: Option<&str>, attrs: &[(&str, &str)]) {
+        self.nodes.push(Node {
+            id: id.to_string(),
+            group: group.map(str::to_string),
+            attributes: attributes(attrs),
+        });
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str, attrs: &[(&str, &str)]) {
+        self.edges.push(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            attributes: attributes(attrs),
+        });
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Dot => self.to_dot(),
+            Format::Mermaid => self.to_mermaid(),
+            Format::Json => self.to_json(),
+        }
+    }
+
+    /// The nodes without a group, then the nodes of each group, in the order they were added.
+    fn groups(&self) -> (Vec<&Node>, Vec<(&str, Vec<&Node>)>) {
+        let mut ungrouped = vec![];
+        let mut groups: Vec<(&str, Vec<&Node>)> = vec![];
+
+        self.nodes.iter().for_each(|node| match &node.group {
+            None => ungrouped.push(node),
+            Some(group) => match groups.iter_mut().find(|(name, _)| name == group) {
+                Some((_, nodes)) => nodes.push(node),
+                None => groups.push((group, vec![node])),
+            },
+        });
+
+        (ungrouped, groups)
+    }
+
+    pub fn to_dot(&self) -> String {
+        let dot_attributes = |attributes: &BTreeMap<String, String>| {
+            if attributes.is_empty() {
+                String::new()
+            } else {
+                let list = attributes
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, quoted(value)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!(" [{}]", list)
+            }
+        };
+        let write_node = |node: &Node, indent: &str| {
+            format!(
+                "{}{}{};\n",
+                indent,
+                quoted(&node.id),
+                dot_attributes(&node.attributes)
+            )
+        };
+
+        let (ungrouped, groups) = self.groups();
+        let mut output = format!("{} G {{\n", if self.directed { "digraph" } else { "graph" });
+
+        groups.iter().for_each(|(group, nodes)| {
+            output.push_str(&format!(
+                "  subgraph {} {{\n",
+                quoted(&format!("cluster_{}", group))
+            ));
+            output.push_str(&format!("    label={};\n", quoted(group)));
+            nodes
+                .iter()
+                .for_each(|node| output.push_str(&write_node(node, "    ")));
+            output.push_str("  }\n");
+        });
+        ungrouped
+            .iter()
+            .for_each(|node| output.push_str(&write_node(node, "  ")));
+
+        let arrow = if self.directed { "->" } else { "--" };
+        self.edges.iter().for_each(|edge| {
+            output.push_str(&format!(
+                "  {} {} {}{};\n",
+                quoted(&edge.from),
+                arrow,
+                quoted(&edge.to),
+                dot_attributes(&edge.attributes)
+            ));
+        });
+
+        output.push_str("}\n");
+        output
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        // mermaid ids cannot contain most punctuation, so the nodes are numbered instead
+        let mut ids = BTreeMap::new();
+        let mut id = |name: &str| {
+            let next = ids.len();
+            format!("n{}", ids.entry(name.to_string()).or_insert(next))
+        };
+        let write_node = |node: &Node, id: String| {
+            let label = node.attributes.get("label").unwrap_or(&node.id);
+            format!("{}[{}]", id, mermaid_quoted(label))
+        };
+
+        let (ungrouped, groups) = self.groups();
+        let mut output = "flowchart LR\n".to_string();
+        let mut styles = vec![];
+
+        groups.iter().for_each(|(group, nodes)| {
+            output.push_str(&format!("  subgraph {}\n", mermaid_quoted(group)));
+            nodes.iter().for_each(|node| {
+                output.push_str(&format!("    {}\n", write_node(node, id(&node.id))));
+            });
+            output.push_str("  end\n");
+        });
+        ungrouped.iter().for_each(|node| {
+            output.push_str(&format!("  {}\n", write_node(node, id(&node.id))));
+        });
+        self.nodes.iter().for_each(|node| {
+            if let Some(color) = node.attributes.get("fillcolor") {
+                styles.push(format!("  style {} fill:{}\n", id(&node.id), color));
+            }
+        });
+
+        let arrow = if self.directed { "-->" } else { "---" };
+        self.edges.iter().for_each(|edge| {
+            let (from, to) = (id(&edge.from), id(&edge.to));
+            match edge.attributes.get("label") {
+                Some(label) => output.push_str(&format!(
+                    "  {} {}|{}| {}\n",
+                    from,
+                    arrow,
+                    mermaid_quoted(label),
+                    to
+                )),
+                None => output.push_str(&format!("  {} {} {}\n", from, arrow, to)),
+            }
+        });
+
+        // nodes that are only used by edges still need their name as text
+        ids.iter()
+            .filter(|(name, _)| !self.nodes.iter().any(|node| node.id == **name))
+            .for_each(|(name, id)| {
+                writeln!(output, "  n{}[{}]", id, mermaid_quoted(name))
+                    .expect("writing to a string");
+            });
+
+        styles.into_iter().for_each(|style| output.push_str(&style));
+        output
+    }
+
+    pub fn to_json(&self) -> String {
+        let json_attributes = |attributes: &BTreeMap<String, String>| {
+            let list = attributes
+                .iter()
+                .map(|(key, value)| format!("{}: {}", quoted(key), quoted(value)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{{{}}}", list)
+        };
+
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    r#"    {{"id": {}, "group": {}, "attributes": {}}}"#,
+                    quoted(&node.id),
+                    node.group.as_deref().map_or("null".to_string(), quoted),
+                    json_attributes(&node.attributes)
+                )
+            })
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                format!(
+                    r#"    {{"from": {}, "to": {}, "attributes": {}}}"#,
+                    quoted(&edge.from),
+                    quoted(&edge.to),
+                    json_attributes(&edge.attributes)
+                )
+            })
+            .collect::<Vec<_>>();
+        let list = |items: Vec<String>| {
+            if items.is_empty() {
+                "[]".to_string()
+            } else {
+                format!("[\n{}\n  ]", items.join(",\n"))
+            }
+        };
+
+        format!(
+            "{{\n  \"directed\": {},\n  \"nodes\": {},\n  \"edges\": {}\n}}\n",
+            self.directed,
+            list(nodes),
+            list(edges)
+        )
+    }
+}
+
+/// A double quoted string, escaped so that it is valid in DOT and JSON.
+fn quoted(text: &str) -> String {
+    let mut result = String::from("\"");
+    text.chars().for_each(|ch| match ch {
+        '"' => result.push_str("\\\""),
+        '\\' => result.push_str("\\\\"),
+        '\n' => result.push_str("\\n"),
+        ch if ch.is_control() => {
+            write!(result, "\\u{:04x}", ch as u32).expect("writing to a string");
+        }
+        ch => result.push(ch),
+    });
+    result.push('"');
+    result
+}
+
+/// A double quoted Mermaid label. Mermaid has no backslash escapes, so quotes and backslashes are
+/// written as entity codes instead, and so is `#`, which would start one.
+fn mermaid_quoted(text: &str) -> String {
+    let mut result = String::from("\"");
+    text.chars().for_each(|ch| match ch {
+        '"' => result.push_str("#quot;"),
+        '\n' => result.push_str("<br>"),
+        '\\' | '#' => write!(result, "#{};", ch as u32).expect("writing to a string"),
+        ch if ch.is_control() => {}
+        ch => result.push(ch),
+    });
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Graph {
+        let mut graph = Graph::new(true);
+        graph.add_node("x00", Some("bit 0"), &[("fillcolor", "green")]);
+        graph.add_node("z00", None, &[("label", "z00 (XOR)")]);
+        graph.add_edge("x00", "z00", &[]);
+        graph.add_edge("y00", "z00", &[]);
+        graph
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            Format::from_path(Path::new("a/graph.dot")),
+            Some(Format::Dot)
+        );
+        assert_eq!(
+            Format::from_path(Path::new("graph.mmd")),
+            Some(Format::Mermaid)
+        );
+        assert_eq!(
+            Format::from_path(Path::new("graph.json")),
+            Some(Format::Json)
+        );
+        assert_eq!(Format::from_path(Path::new("graph.svg")), None);
+        assert_eq!(Format::from_path(Path::new("graph")), None);
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(
+            sample().to_dot(),
+            r#"digraph G {
+  subgraph "cluster_bit 0" {
+    label="bit 0";
+    "x00" [fillcolor="green"];
+  }
+  "z00" [label="z00 (XOR)"];
+  "x00" -> "z00";
+  "y00" -> "z00";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_mermaid() {
+        assert_eq!(
+            sample().to_mermaid(),
+            r#"flowchart LR
+  subgraph "bit 0"
+    n0["x00"]
+  end
+  n1["z00 (XOR)"]
+  n0 --> n1
+  n2 --> n1
+  n2["y00"]
+  style n0 fill:green
+"#
+        );
+    }
+
+    #[test]
+    fn test_mermaid_escapes() {
+        assert_eq!(
+            mermaid_quoted("say \"hi\"\\ #1\nok"),
+            r#""say #quot;hi#quot;#92; #35;1<br>ok""#
+        );
+        let mut graph = Graph::new(true);
+        graph.add_node("a\"b", None, &[("label", "\\")]);
+        assert_eq!(graph.to_mermaid(), "flowchart LR\n  n0[\"#92;\"]\n");
+    }
+
+    #[test]
+    fn test_json() {
+        let mut graph = Graph::new(false);
+        graph.add_edge("a\"b", "c", &[("weight", "1")]);
+        assert_eq!(
+            graph.to_json(),
+            r#"{
+  "directed": false,
+  "nodes": [],
+  "edges": [
+    {"from": "a\"b", "to": "c", "attributes": {"weight": "1"}}
+  ]
+}
+"#
+        );
+    }
+}
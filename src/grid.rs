@@ -0,0 +1,253 @@
+//! A rectangular 2D grid, shared by the days whose input is a map.
+//!
+//! Positions are [`IVec2`], with `x` going right and `y` going down. Positions outside of the grid
+//! (including negative ones) are never an error for [`Grid::get`], so callers can step off the edge
+//! freely and treat `None` as "out of bounds".
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use glam::IVec2;
+
+use crate::{parse, ParseError};
+
+/// The 4 orthogonal neighbour offsets: up, right, down, left.
+pub const DELTAS_4: [IVec2; 4] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 0),
+];
+
+/// The 8 neighbour offsets, clockwise from up.
+pub const DELTAS_8: [IVec2; 8] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(1, 0),
+    IVec2::new(1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, 0),
+    IVec2::new(-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(IVec2) -> T) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| IVec2::new(x as i32, y as i32)))
+                .map(&mut f)
+                .collect(),
+        }
+    }
+
+    /// Parses one cell per character, where `cell` returns `None` for characters that are not
+    /// allowed in the grid. Every row must have the same width.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.trim().lines() {
+            let line = line.trim();
+            let mut row_width = 0;
+
+            for (_, ch, text) in parse::row_chars(line) {
+                cells.push(cell(ch).ok_or_else(|| parse::unexpected_char(text))?);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        line,
+                        format!("expected a row of {} characters", width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> IVec2 {
+        IVec2::new(self.width as i32, self.height as i32)
+    }
+
+    pub fn in_bounds(&self, pos: IVec2) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    fn index_of(&self, pos: IVec2) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| IVec2::new(x as i32, y as i32)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` as `chunks` does not accept 0, and an empty grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// The orthogonal neighbours of `pos` that are in bounds.
+    pub fn neighbours_4(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        DELTAS_4
+            .into_iter()
+            .map(move |delta| pos + delta)
+            .filter(|pos| self.in_bounds(*pos))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are in bounds.
+    pub fn neighbours_8(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        DELTAS_8
+            .into_iter()
+            .map(move |delta| pos + delta)
+            .filter(|pos| self.in_bounds(*pos))
+    }
+
+    /// Position of the first cell (row by row) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<IVec2>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid where any character is allowed.
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, Some)
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, pos: IVec2) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is out of bounds", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse_chars("\n#..\n.@#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.find(&'@'), Some(IVec2::new(1, 1)));
+        assert_eq!(grid.to_string(), "#..\n.@#");
+        assert_eq!(grid.column(2).collect::<String>(), ".#");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "#..\n.x.";
+        let err = Grid::parse(input, |ch| (ch != 'x').then_some(ch)).unwrap_err();
+        assert_eq!(err.locate(input).position.map(|pos| pos.col), Some(2));
+        assert!(Grid::parse_chars("#..\n.#").is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.get(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get(IVec2::new(2, 0)), None);
+        assert_eq!(grid.get(IVec2::new(1, 2)), Some(&0));
+        assert_eq!(grid.neighbours_4(IVec2::ZERO).count(), 2);
+        assert_eq!(grid.neighbours_8(IVec2::new(1, 1)).count(), 5);
+    }
+}
@@ -0,0 +1,217 @@
+//! Runtime loading of puzzle inputs.
+//!
+//! Inputs used to be embedded with `include_str!`, which meant that the crate did not compile
+//! without the private `actual_inputs` submodule. The input is now resolved when a solution runs,
+//! in this order:
+//!
+//! 1. a path given to the runner with `--input` (`-` reads from stdin),
+//! 2. the directory named by the `AOC_INPUT_DIR` environment variable, laid out like the
+//!    submodule (`<dir>/2024/NN/input.txt`),
+//! 3. the `actual_inputs` submodule in the repository.
+//!
+//! A day can have several inputs, one per profile (e.g. one per member of the team), stored next
+//! to each other as `<dir>/2024/NN/<profile>.txt`. The profile named `input` is the default.
+
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const YEAR: u32 = 2024;
+
+/// Environment variable that overrides the root directory of the inputs.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Profile read when none is given, which is the only input of the `actual_inputs` layout.
+pub const DEFAULT_PROFILE: &str = "input";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing { day: u8, path: PathBuf },
+    /// The input file exists, but cannot be read.
+    Unreadable { path: PathBuf, source: io::Error },
+    /// Stdin was requested, but cannot be read.
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { day, path } => write!(
+                f,
+                "no input for day {:02}: {} does not exist (check out the actual_inputs \
+                 submodule, set {} or pass the input path with --input)",
+                day,
+                path.display(),
+                INPUT_DIR_ENV
+            ),
+            InputError::Unreadable { path, source } => {
+                write!(f, "cannot read input {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "cannot read input from stdin: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// A profile in `AOC_INPUT_DIR` if set, otherwise in the `actual_inputs` submodule.
+    Profile(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Profile(DEFAULT_PROFILE.to_string())
+    }
+}
+
+impl InputSource {
+    /// Picks the source from the `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => InputSource::default(),
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_file(day, path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Profile(profile) => read_file(day, &profile_path(day, profile)),
+        }
+    }
+}
+
+fn read_file(day: u8, path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| {
+        if source.kind() == io::ErrorKind::NotFound {
+            InputError::Missing {
+                day,
+                path: path.to_path_buf(),
+            }
+        } else {
+            InputError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            }
+        }
+    })
+}
+
+pub fn input_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("actual_inputs"))
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    input_dir()
+        .join(YEAR.to_string())
+        .join(format!("{:02}", day))
+}
+
+pub fn profile_path(day: u8, profile: &str) -> PathBuf {
+    day_dir(day).join(format!("{}.txt", profile))
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    profile_path(day, DEFAULT_PROFILE)
+}
+
+/// The profiles that have an input for `day`, sorted by name with the default profile first.
+pub fn profiles(day: u8) -> Vec<String> {
+    profiles_in(&day_dir(day))
+}
+
+fn profiles_in(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut profiles = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect::<Vec<_>>();
+    profiles.sort_unstable_by_key(|profile| (profile != DEFAULT_PROFILE, profile.clone()));
+    profiles
+}
+
+/// Input for the `*_actual` tests. Returns `None` when the input is not available, so that the
+/// test can skip itself instead of failing.
+pub fn actual_input(day: u8) -> Option<String> {
+    match InputSource::default().read(day) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("skipping test: {}", err);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(
+            InputSource::from_arg(None),
+            InputSource::Profile("input".to_string())
+        );
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("a/b.txt")),
+            InputSource::File(PathBuf::from("a/b.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_path_layout() {
+        assert!(default_path(7).ends_with("2024/07/input.txt"));
+        assert!(profile_path(7, "alice").ends_with("2024/07/alice.txt"));
+    }
+
+    #[test]
+    fn test_profiles() {
+        let dir = std::env::temp_dir().join("aoc_2024_test_profiles");
+        std::fs::create_dir_all(&dir).unwrap();
+        ["bob.txt", "input.txt", "alice.txt", "notes.md"]
+            .into_iter()
+            .for_each(|file| std::fs::write(dir.join(file), "").unwrap());
+
+        assert_eq!(profiles_in(&dir), ["input", "alice", "bob"]);
+        assert!(profiles_in(&dir.join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = InputSource::File(PathBuf::from("does/not/exist.txt"))
+            .read(3)
+            .unwrap_err();
+        assert!(matches!(err, InputError::Missing { day: 3, .. }));
+        assert!(err.to_string().starts_with("no input for day 03"));
+    }
+}
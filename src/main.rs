@@ -1,3 +1,788 @@
-fn main() {
-    println!("Hello, world!");
+use std::{ops::Range, path::PathBuf, process::ExitCode, time::Instant};
+
+use aoc_2024::{
+    animation,
+    answers::{self, Answers, Status},
+    bench,
+    export::Format,
+    input::{self, InputSource, DEFAULT_PROFILE},
+    params::Params,
+    trace::{self, Sink},
+    DynParsed,
+};
+
+const USAGE: &str = "\
+Usage: aoc <COMMAND> [OPTIONS]
+
+Commands:
+  run      Run the solutions and print their answers
+  bench    Time the parse and both parts of the solutions
+  verify   Check the answers against the answers.toml manifest
+  replay   Step through the simulation of a day (day 15)
+  help     Print this message
+
+Options for `run`:
+  --day <DAY>      Only run this day (default: every day)
+  --part <PART>    Only run this part, 1 or 2 (default: both)
+  --input <PATH>   Read the input from PATH, or from stdin if PATH is `-`
+                   (default: $AOC_INPUT_DIR or the actual_inputs submodule)
+  --profile <NAME> Read the input of another profile, <dir>/2024/NN/<NAME>.txt
+                   (default: input)
+  --quiet          Only print the answers, without the day, part and timing
+  --export <PATH>  Also save the graph of the input (days 23 and 24) to PATH, as
+                   DOT (.dot), Mermaid (.mmd) or JSON (.json)
+  --animate <PATH> Also save the simulation of the input (day 14) to PATH, as an
+                   animated GIF (.gif), one image per step (.pbm or .pgm, numbered
+                   after PATH) or an ASCII flipbook (.txt)
+  --frames <START>..<END>
+                   The steps to animate (default: 0..100)
+  --every <N>      Only keep every N-th step of the animation (default: 1)
+  --param <NAME>=<VALUE>
+                   Set a parameter of the day, e.g. `--param width=11` for day 14
+                   (repeatable)
+  --trace          Print the diagnostics of the solutions (e.g. the picture of
+                   day 14) to stderr
+  --trace-file <PATH>
+                   Save the diagnostics of the solutions to PATH instead
+
+Options for `bench`:
+  --day <DAY>      Only time this day (default: every day)
+  --input <PATH>   Same as for `run`
+  --profile <NAME> Same as for `run`
+  --runs <RUNS>    Number of times each step is repeated (default: 10)
+  --json <PATH>    Where to save the results (default: bench.json)
+
+Options for `verify`:
+  --day <DAY>      Only check this day (default: every day)
+  --input <PATH>   Check this input against the answers of the default profile
+  --profile <NAME> Only check this profile (default: every profile with an input
+                   or an answer)
+
+Options for `replay`:
+  --day <DAY>      The day to step through (required)
+  --input <PATH>   Same as for `run`, but not stdin, which is used for the keys
+  --profile <NAME> Same as for `run`
+  --param <NAME>=<VALUE>
+                   Same as for `run`, e.g. `--param widen=2` for day 15
+
+Press enter to advance by one step, type a number then enter to advance by that
+many steps, or q to quit.";
+
+fn parse_day(day: String) -> Result<u8, String> {
+    day.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day {}, expected 1 to 25", day))
+}
+
+fn check_input_args(
+    day: Option<u8>,
+    input: &Option<String>,
+    profile: &Option<String>,
+) -> Result<(), String> {
+    if input.is_some() && day.is_none() {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    if input.is_some() && profile.is_some() {
+        return Err("--input and --profile cannot be used together".to_string());
+    }
+    Ok(())
+}
+
+fn input_source(input: &Option<String>, profile: &Option<String>) -> InputSource {
+    match profile {
+        Some(profile) => InputSource::Profile(profile.clone()),
+        None => InputSource::from_arg(input.as_deref()),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    profile: Option<String>,
+    quiet: bool,
+    export: Option<(PathBuf, Format)>,
+    trace: Sink,
+    params: Params,
+    animate: Option<(PathBuf, animation::Format)>,
+    frames: Range<usize>,
+    every: usize,
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        Self {
+            day: None,
+            part: None,
+            input: None,
+            profile: None,
+            quiet: false,
+            export: None,
+            trace: Sink::default(),
+            params: Params::default(),
+            animate: None,
+            frames: 0..100,
+            every: 1,
+        }
+    }
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+
+            match arg.as_str() {
+                "--day" => result.day = Some(parse_day(value("--day")?)?),
+                "--part" => {
+                    let part = value("--part")?;
+                    result.part = Some(
+                        part.parse()
+                            .ok()
+                            .filter(|part| (1..=2).contains(part))
+                            .ok_or_else(|| format!("invalid part {}, expected 1 or 2", part))?,
+                    );
+                }
+                "--input" => result.input = Some(value("--input")?),
+                "--profile" => result.profile = Some(value("--profile")?),
+                "--quiet" => result.quiet = true,
+                "--export" => {
+                    let path = PathBuf::from(value("--export")?);
+                    let format = Format::from_path(&path).ok_or_else(|| {
+                        format!(
+                            "cannot export to {}, expected a .dot, .mmd or .json file",
+                            path.display()
+                        )
+                    })?;
+                    result.export = Some((path, format));
+                }
+                "--animate" => {
+                    let path = PathBuf::from(value("--animate")?);
+                    let format = animation::Format::from_path(&path).ok_or_else(|| {
+                        format!(
+                            "cannot animate to {}, expected a .gif, .pbm, .pgm or .txt file",
+                            path.display()
+                        )
+                    })?;
+                    result.animate = Some((path, format));
+                }
+                "--frames" => {
+                    let frames = value("--frames")?;
+                    result.frames = frames
+                        .split_once("..")
+                        .and_then(|(start, end)| Some(start.parse().ok()?..end.parse().ok()?))
+                        .filter(|frames| !frames.is_empty())
+                        .ok_or_else(|| format!("invalid frames {}, expected START..END", frames))?;
+                }
+                "--every" => {
+                    let every = value("--every")?;
+                    result.every = every
+                        .parse()
+                        .ok()
+                        .filter(|every| *every > 0)
+                        .ok_or_else(|| format!("invalid --every {}, expected at least 1", every))?;
+                }
+                "--param" => result.params.insert_arg(&value("--param")?)?,
+                "--trace" => result.trace = Sink::Stderr,
+                "--trace-file" => result.trace = Sink::File(PathBuf::from(value("--trace-file")?)),
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        check_input_args(result.day, &result.input, &result.profile)?;
+        if result.export.is_some() && result.day.is_none() {
+            return Err("--export can only be used together with --day".to_string());
+        }
+        if result.animate.is_some() && result.day.is_none() {
+            return Err("--animate can only be used together with --day".to_string());
+        }
+        if !result.params.is_empty() && result.day.is_none() {
+            return Err("--param can only be used together with --day".to_string());
+        }
+        Ok(result)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct BenchArgs {
+    day: Option<u8>,
+    input: Option<String>,
+    profile: Option<String>,
+    runs: usize,
+    json: String,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        Self {
+            day: None,
+            input: None,
+            profile: None,
+            runs: 10,
+            json: "bench.json".to_string(),
+        }
+    }
+}
+
+impl BenchArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+
+            match arg.as_str() {
+                "--day" => result.day = Some(parse_day(value("--day")?)?),
+                "--input" => result.input = Some(value("--input")?),
+                "--profile" => result.profile = Some(value("--profile")?),
+                "--runs" => {
+                    let runs = value("--runs")?;
+                    result.runs = runs
+                        .parse()
+                        .ok()
+                        .filter(|runs| *runs > 0)
+                        .ok_or_else(|| format!("invalid runs {}, expected at least 1", runs))?;
+                }
+                "--json" => result.json = value("--json")?,
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        check_input_args(result.day, &result.input, &result.profile)?;
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ReplayArgs {
+    day: u8,
+    input: Option<String>,
+    profile: Option<String>,
+    params: Params,
+}
+
+impl ReplayArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut result = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+
+            match arg.as_str() {
+                "--day" => day = Some(parse_day(value("--day")?)?),
+                "--input" => result.input = Some(value("--input")?),
+                "--profile" => result.profile = Some(value("--profile")?),
+                "--param" => result.params.insert_arg(&value("--param")?)?,
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        check_input_args(day, &result.input, &result.profile)?;
+        if result.input.as_deref() == Some("-") {
+            return Err("replay reads the keys from stdin, so not the input".to_string());
+        }
+        result.day = day.ok_or("--day is required")?;
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VerifyArgs {
+    day: Option<u8>,
+    input: Option<String>,
+    profile: Option<String>,
+}
+
+impl VerifyArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut result = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+
+            match arg.as_str() {
+                "--day" => result.day = Some(parse_day(value("--day")?)?),
+                "--input" => result.input = Some(value("--input")?),
+                "--profile" => result.profile = Some(value("--profile")?),
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        check_input_args(result.day, &result.input, &result.profile)?;
+        Ok(result)
+    }
+}
+
+fn run_part(day: u8, parsed: &dyn DynParsed, part: u8, quiet: bool) {
+    let start = Instant::now();
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    let elapsed = start.elapsed();
+
+    if quiet {
+        println!("{}", answer);
+    } else {
+        println!("day {:02} part {}: {} ({:.2?})", day, part, answer, elapsed);
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let source = input_source(&args.input, &args.profile);
+    if let Err(err) = trace::set_sink(args.trace.clone()) {
+        eprintln!("error: cannot open the trace file: {}", err);
+        return ExitCode::FAILURE;
+    }
+    let mut failed = false;
+
+    aoc_2024::solutions()
+        .into_iter()
+        .filter(|solution| args.day.is_none_or(|day| solution.day() == day))
+        .for_each(|solution| {
+            let input = match source.read(solution.day()) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    failed = true;
+                    return;
+                }
+            };
+
+            if let Some(name) = args
+                .params
+                .names()
+                .find(|name| !solution.params().contains(name))
+            {
+                eprintln!("error: day {:02} has no parameter {}", solution.day(), name);
+                failed = true;
+                return;
+            }
+
+            let parsed = match solution.parse_with(&input, &args.params) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    eprintln!("error: malformed input, {}", err.diagnostic());
+                    failed = true;
+                    return;
+                }
+            };
+
+            if let Some((path, format)) = &args.export {
+                let exported = match parsed.graph() {
+                    Some(graph) => std::fs::write(path, graph.render(*format))
+                        .map_err(|err| format!("cannot write {}: {}", path.display(), err)),
+                    None => Err(format!("day {:02} has no graph to export", solution.day())),
+                };
+                match exported {
+                    Ok(()) => eprintln!("graph exported to {}", path.display()),
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        failed = true;
+                    }
+                }
+            }
+
+            if let Some((path, format)) = &args.animate {
+                let animated = match parsed.frames(args.frames.clone()) {
+                    Some(frames) => animation::save(path, *format, frames.step_by(args.every))
+                        .map_err(|err| format!("cannot write {}: {}", path.display(), err)),
+                    None => Err(format!("day {:02} has nothing to animate", solution.day())),
+                };
+                match animated {
+                    Ok(count) => eprintln!("{} frames saved to {}", count, path.display()),
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        failed = true;
+                    }
+                }
+            }
+
+            [1, 2]
+                .into_iter()
+                .filter(|part| args.part.is_none_or(|only| only == *part))
+                .filter(|part| *part == 1 || solution.has_part2())
+                .for_each(|part| run_part(solution.day(), parsed.as_ref(), part, args.quiet));
+        });
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_bench(args: BenchArgs) -> ExitCode {
+    let source = input_source(&args.input, &args.profile);
+    let mut failed = false;
+
+    let results = aoc_2024::solutions()
+        .into_iter()
+        .filter(|solution| args.day.is_none_or(|day| solution.day() == day))
+        .filter_map(|solution| {
+            let result = source
+                .read(solution.day())
+                .map_err(|err| err.to_string())
+                .and_then(|input| {
+                    bench::bench(solution, &input, args.runs)
+                        .map_err(|err| format!("malformed input, {}", err.diagnostic()))
+                });
+
+            result
+                .inspect_err(|err| {
+                    eprintln!("error: {}", err);
+                    failed = true;
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+    println!("{}", bench::table(&results));
+
+    match std::fs::write(&args.json, bench::to_json(&results, args.runs)) {
+        Ok(()) => println!("\nresults saved to {}", args.json),
+        Err(err) => {
+            eprintln!("error: cannot write {}: {}", args.json, err);
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_verify(args: VerifyArgs) -> ExitCode {
+    let answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let checks = aoc_2024::solutions()
+        .into_iter()
+        .filter(|solution| args.day.is_none_or(|day| solution.day() == day))
+        .flat_map(|solution| {
+            let day = solution.day();
+            let profiles = match (&args.input, &args.profile) {
+                (Some(_), _) => vec![DEFAULT_PROFILE.to_string()],
+                (None, Some(profile)) => vec![profile.clone()],
+                (None, None) => {
+                    let mut profiles = input::profiles(day);
+                    answers.profiles(day).into_iter().for_each(|profile| {
+                        if !profiles.iter().any(|known| known == profile) {
+                            profiles.push(profile.to_string());
+                        }
+                    });
+                    profiles
+                }
+            };
+
+            profiles
+                .into_iter()
+                .flat_map(|profile| {
+                    let source = match &args.input {
+                        Some(input) => InputSource::from_arg(Some(input)),
+                        None => InputSource::Profile(profile.clone()),
+                    };
+                    answers::verify(solution, &source, &profile, &answers)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let width = checks
+        .iter()
+        .map(|check| check.profile.len())
+        .max()
+        .unwrap_or(0)
+        .max("profile".len());
+    println!("{:<4} {:<width$} {:<5} status", "day", "profile", "part");
+    checks.iter().for_each(|check| {
+        println!(
+            "{:02}   {:<width$} {:<5} {}",
+            check.day, check.profile, check.part, check.status
+        );
+    });
+
+    let count =
+        |matches: fn(&Status) -> bool| checks.iter().filter(|check| matches(&check.status)).count();
+    let failed = count(|status| matches!(status, Status::Fail { .. }));
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(|status| *status == Status::Pass),
+        failed,
+        count(|status| matches!(status, Status::Missing(_)))
+    );
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_replay(args: ReplayArgs) -> ExitCode {
+    let solution = aoc_2024::solution(args.day).expect("the day was checked when parsing");
+    let source = input_source(&args.input, &args.profile);
+
+    let input = match source.read(args.day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(name) = args
+        .params
+        .names()
+        .find(|name| !solution.params().contains(name))
+    {
+        eprintln!("error: day {:02} has no parameter {}", args.day, name);
+        return ExitCode::FAILURE;
+    }
+    let parsed = match solution.parse_with(&input, &args.params) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: malformed input, {}", err.diagnostic());
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(mut replay) = parsed.replay() else {
+        eprintln!("error: day {:02} has nothing to replay", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    println!("{}", replay.render());
+    for line in std::io::stdin().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let steps = match line.trim() {
+            "" => 1,
+            "q" => break,
+            count => match count.parse::<usize>() {
+                Ok(count) => count,
+                Err(_) => {
+                    eprintln!("press enter, type a number of steps, or q to quit");
+                    continue;
+                }
+            },
+        };
+
+        let descriptions = (0..steps).map_while(|_| replay.step()).collect::<Vec<_>>();
+        println!("{}", replay.render());
+        match descriptions.last() {
+            Some(description) => println!("{}", description),
+            None => {
+                println!("the end");
+                break;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => match RunArgs::parse(args) {
+            Ok(args) => run(args),
+            Err(err) => {
+                eprintln!("error: {}\n\n{}", err, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some("bench") => match BenchArgs::parse(args) {
+            Ok(args) => run_bench(args),
+            Err(err) => {
+                eprintln!("error: {}\n\n{}", err, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some("verify") => match VerifyArgs::parse(args) {
+            Ok(args) => run_verify(args),
+            Err(err) => {
+                eprintln!("error: {}\n\n{}", err, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some("replay") => match ReplayArgs::parse(args) {
+            Ok(args) => run_replay(args),
+            Err(err) => {
+                eprintln!("error: {}\n\n{}", err, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Some(command) => {
+            eprintln!("error: unknown command {}\n\n{}", command, USAGE);
+            ExitCode::from(2)
+        }
+        None => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<RunArgs, String> {
+        RunArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_run_args() {
+        assert_eq!(parse(&[]), Ok(RunArgs::default()));
+        assert_eq!(
+            parse(&["--day", "16", "--part", "2", "--input", "in.txt", "--quiet"]),
+            Ok(RunArgs {
+                day: Some(16),
+                part: Some(2),
+                input: Some("in.txt".to_string()),
+                profile: None,
+                quiet: true,
+                export: None,
+                trace: Sink::Null,
+                params: Params::default(),
+                animate: None,
+                frames: 0..100,
+                every: 1,
+            })
+        );
+        assert_eq!(
+            parse(&["--day", "24", "--export", "graph.dot"]).map(|args| args.export),
+            Ok(Some((PathBuf::from("graph.dot"), Format::Dot)))
+        );
+        assert_eq!(parse(&["--trace"]).map(|args| args.trace), Ok(Sink::Stderr));
+        assert_eq!(
+            parse(&["--trace-file", "trace.txt"]).map(|args| args.trace),
+            Ok(Sink::File(PathBuf::from("trace.txt")))
+        );
+
+        assert_eq!(
+            parse(&[
+                "--day",
+                "14",
+                "--animate",
+                "robots.gif",
+                "--frames",
+                "7000..7100"
+            ])
+            .map(|args| (args.animate, args.frames)),
+            Ok((
+                Some((PathBuf::from("robots.gif"), animation::Format::Gif)),
+                7000..7100
+            ))
+        );
+
+        let mut params = Params::default();
+        params.insert_arg("width=11").unwrap();
+        params.insert_arg("height=7").unwrap();
+        assert_eq!(
+            parse(&["--day", "14", "--param", "width=11", "--param", "height=7"])
+                .map(|args| args.params),
+            Ok(params)
+        );
+    }
+
+    #[test]
+    fn test_parse_run_args_invalid() {
+        assert!(parse(&["--day", "26"]).is_err());
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--input", "in.txt"]).is_err());
+        assert!(parse(&["--day", "1", "--input", "in.txt", "--profile", "alice"]).is_err());
+        assert!(parse(&["--export", "graph.dot"]).is_err());
+        assert!(parse(&["--param", "width=11"]).is_err());
+        assert!(parse(&["--animate", "robots.gif"]).is_err());
+        assert!(parse(&["--day", "14", "--frames", "10..5"]).is_err());
+        assert!(parse(&["--day", "14", "--every", "0"]).is_err());
+        assert!(parse(&["--day", "14", "--param", "width"]).is_err());
+        assert!(parse(&["--day", "24", "--export", "graph.svg"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        let parse = |args: &[&str]| BenchArgs::parse(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(parse(&[]), Ok(BenchArgs::default()));
+        assert_eq!(
+            parse(&["--day", "6", "--runs", "3", "--json", "before.json"]),
+            Ok(BenchArgs {
+                day: Some(6),
+                input: None,
+                profile: None,
+                runs: 3,
+                json: "before.json".to_string(),
+            })
+        );
+        assert!(parse(&["--runs", "0"]).is_err());
+        assert!(parse(&["--input", "in.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        let parse = |args: &[&str]| VerifyArgs::parse(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(parse(&[]), Ok(VerifyArgs::default()));
+        assert_eq!(
+            parse(&["--day", "3", "--profile", "alice"]),
+            Ok(VerifyArgs {
+                day: Some(3),
+                input: None,
+                profile: Some("alice".to_string()),
+            })
+        );
+        assert!(parse(&["--part", "1"]).is_err());
+        assert!(parse(&["--input", "in.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_replay_args() {
+        let parse = |args: &[&str]| ReplayArgs::parse(args.iter().map(|arg| arg.to_string()));
+
+        let mut params = Params::default();
+        params.insert_arg("widen=2").unwrap();
+        assert_eq!(
+            parse(&["--day", "15", "--param", "widen=2"]),
+            Ok(ReplayArgs {
+                day: 15,
+                input: None,
+                profile: None,
+                params,
+            })
+        );
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--day", "15", "--input", "-"]).is_err());
+    }
 }
@@ -0,0 +1,68 @@
+//! Parameters of a day that are not part of its input, given to the runner with
+//! `--param <NAME>=<VALUE>`, e.g. the size of the room of day 14 to run it on the sample.
+//!
+//! A day lists the names it understands in [`Solution::params`](crate::Solution::params), and
+//! reads them in [`Solution::parse_with`](crate::Solution::parse_with), falling back to the values
+//! of the actual puzzle.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Adds a parameter from an argument like `width=11`.
+    pub fn insert_arg(&mut self, arg: &str) -> Result<(), String> {
+        match arg.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                self.values.insert(name.to_string(), value.to_string());
+                Ok(())
+            }
+            _ => Err(format!("invalid parameter {}, expected NAME=VALUE", arg)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// The value of `name`, or `default` if it was not given.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, ParseError> {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| ParseError::new(value, format!("invalid value for {}", name))),
+            None => Ok(default),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::default();
+        params.insert_arg("width=11").unwrap();
+        params.insert_arg("height=x").unwrap();
+        assert!(params.insert_arg("width").is_err());
+        assert!(params.insert_arg("=11").is_err());
+
+        assert_eq!(params.names().collect::<Vec<_>>(), ["height", "width"]);
+        assert_eq!(params.get("width", 101), Ok(11));
+        assert_eq!(params.get("time", 100), Ok(100));
+        assert_eq!(
+            params.get("height", 103).map_err(|err| err.message),
+            Err("invalid value for height".to_string())
+        );
+    }
+}
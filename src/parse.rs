@@ -0,0 +1,217 @@
+//! Errors and helpers for parsing puzzle inputs.
+//!
+//! Parsers report an error by handing [`ParseError::new`] the offending slice of the input. The
+//! line and column are worked out afterwards by [`ParseError::locate`], which the solution registry
+//! calls with the whole input, so parsers of nested sections (e.g. one block of day 13) do not need
+//! to know where their section starts.
+
+use std::{fmt::Display, str::FromStr};
+
+/// Error returned when a puzzle input is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by [`ParseError::in_day`].
+    pub day: Option<u8>,
+    /// Filled in by [`ParseError::locate`], if the offending text is part of the input.
+    pub position: Option<Position>,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+    /// Address range of the offending text, used to find it in the input.
+    span: (usize, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    /// Starts from 1.
+    pub line: usize,
+    /// Starts from 1, counted in characters.
+    pub col: usize,
+    /// The whole line containing the offending text.
+    pub line_text: String,
+}
+
+impl ParseError {
+    /// `text` should be a slice of the input, so that the error can be located later. For
+    /// something that is missing, pass the (possibly empty) slice where it was expected.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        let start = text.as_ptr() as usize;
+        Self {
+            day: None,
+            position: None,
+            text: text.to_string(),
+            message: message.into(),
+            span: (start, start + text.len()),
+        }
+    }
+
+    pub fn in_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Works out the line and column of the offending text in `input`. Does nothing if the text
+    /// is not a slice of `input` (e.g. it came from an owned copy).
+    pub fn locate(self, input: &str) -> Self {
+        let input_start = input.as_ptr() as usize;
+        let (start, end) = self.span;
+
+        if self.position.is_some() || start < input_start || end > input_start + input.len() {
+            return self;
+        }
+
+        let offset = start - input_start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        Self {
+            position: Some(Position {
+                line: input[..line_start].matches('\n').count() + 1,
+                col: input[line_start..offset].chars().count() + 1,
+                line_text: input[line_start..line_end]
+                    .trim_end_matches('\r')
+                    .to_string(),
+            }),
+            ..self
+        }
+    }
+
+    /// The error followed by the offending line, with the offending text underlined by carets.
+    pub fn diagnostic(&self) -> String {
+        let Some(position) = &self.position else {
+            return self.to_string();
+        };
+
+        let number = position.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.text.trim_end_matches('\r').chars().count().max(1));
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            number,
+            position.line_text,
+            gutter,
+            " ".repeat(position.col - 1),
+            carets
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        if let Some(position) = &self.position {
+            write!(f, "line {}, column {}: ", position.line, position.col)?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ", found {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text` as a number.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, "expected a number"))
+}
+
+/// Parses every number in `text` separated by `delimiter`.
+pub fn numbers<T: FromStr>(text: &str, delimiter: &str) -> Result<Vec<T>, ParseError> {
+    text.split(delimiter).map(number).collect()
+}
+
+/// Like [`str::split_once`], but reports the missing delimiter as an error.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("expected {:?}", delimiter)))
+}
+
+/// Like [`str::strip_prefix`], but reports the missing prefix as an error.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(text, format!("expected {:?}", prefix)))
+}
+
+/// Splits `text` at the first blank line.
+pub fn split_sections(text: &str) -> Result<(&str, &str), ParseError> {
+    text.split_once("\n\n").ok_or_else(|| {
+        let message = if text.contains('\r') {
+            "expected a blank line between sections (the input has CRLF line endings)"
+        } else {
+            "expected a blank line between sections"
+        };
+        ParseError::new(end_of(text), message)
+    })
+}
+
+/// The empty slice at the end of `text`, for reporting something missing at the end.
+pub fn end_of(text: &str) -> &str {
+    &text[text.len()..]
+}
+
+/// Characters of a grid row, each with its column and the slice it came from, so that an
+/// unexpected character can be reported with [`unexpected_char`].
+pub fn row_chars(row: &str) -> impl Iterator<Item = (usize, char, &str)> {
+    row.char_indices()
+        .enumerate()
+        .map(|(x, (idx, ch))| (x, ch, &row[idx..idx + ch.len_utf8()]))
+}
+
+pub fn unexpected_char(text: &str) -> ParseError {
+    ParseError::new(text, "unexpected character")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1 2\n3 x4\n5 6";
+        let err = number::<u32>(&input[6..8]).unwrap_err().locate(input);
+        assert_eq!(
+            err.position,
+            Some(Position {
+                line: 2,
+                col: 3,
+                line_text: "3 x4".to_string()
+            })
+        );
+        assert_eq!(
+            err.in_day(1).to_string(),
+            "day 01, line 2, column 3: expected a number, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn test_locate_outside_input() {
+        let owned = "x".to_string();
+        let err = number::<u32>(&owned).unwrap_err().locate("1 2");
+        assert_eq!(err.position, None);
+        assert_eq!(err.to_string(), "expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "190: 10 19\r\n3267: 81 4O 27\r\n";
+        let err = numbers::<u64>(&input[18..26], " ")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            err.diagnostic(),
+            "line 2, column 10: expected a number, found \"4O\"\n  |\n2 | 3267: 81 4O 27\n  |          ^^"
+        );
+    }
+}
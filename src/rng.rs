@@ -0,0 +1,50 @@
+//! A small pseudo random generator, xorshift64, for fuzzing circuits and generating test inputs.
+//! The same seed always gives the same numbers, so a failure can be reproduced from its seed.
+
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// A seed of 0 would only ever give zeros, so it is replaced by a fixed one.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 0x2024 } else { seed },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A number in `0..bound`, slightly biased towards the low ones unless `bound` is a power of
+    /// two. Panics if `bound` is 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xorshift() {
+        let mut rng = XorShift::new(1);
+        assert_eq!(rng.next_u64(), 1082269761);
+        assert_eq!(rng.next_u64(), 1152992998833853505);
+
+        let numbers = |seed| {
+            let mut rng = XorShift::new(seed);
+            (0..100).map(|_| rng.below(10)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(42), numbers(42));
+        assert_ne!(numbers(42), numbers(43));
+        assert!(numbers(0).iter().any(|number| *number != 0));
+        assert!(numbers(42).iter().all(|number| *number < 10));
+    }
+}
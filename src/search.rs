@@ -0,0 +1,340 @@
+//! Shortest path searches over an implicit graph, given as a start state and a function returning
+//! the neighbours of a state.
+//!
+//! All searches stop once every goal state of the optimal cost has been reached. With
+//! [`Predecessors::All`], every optimal predecessor of a state is kept, so that questions like
+//! "which tiles are on any of the best paths" can be answered with
+//! [`Search::all_optimal_states`].
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use ahash::{HashMap, HashMapExt, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predecessors {
+    /// Only the first predecessor found for a state, which is enough for a single best path.
+    First,
+    /// Every predecessor that reaches a state at its optimal cost. Edge costs must be positive.
+    All,
+}
+
+/// The outcome of a search.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            costs: [(start, zero)].into_iter().collect(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    /// Cost of the best path to a goal, `None` if no goal can be reached.
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// The goal states reached at the optimal cost. With [`Predecessors::First`], only the first
+    /// one is found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Cost of the best path to `state` found before the search stopped. States further than the
+    /// goal may not have their optimal cost.
+    pub fn cost_to(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// One of the best paths to a goal, from the start to the goal.
+    pub fn path(&self) -> Option<Vec<S>> {
+        let mut path = vec![self.goals.first()?.clone()];
+
+        while let Some(previous) = self
+            .predecessors
+            .get(path.last().expect("not empty"))
+            .and_then(|predecessors| predecessors.first())
+        {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any of the best paths to a goal. Needs [`Predecessors::All`] to be
+    /// complete.
+    pub fn all_optimal_states(&self) -> HashSet<S> {
+        let mut visited = self.goals.iter().cloned().collect::<HashSet<_>>();
+        let mut to_process = self.goals.clone();
+
+        while let Some(state) = to_process.pop() {
+            self.predecessors
+                .get(&state)
+                .into_iter()
+                .flatten()
+                .for_each(|previous| {
+                    if visited.insert(previous.clone()) {
+                        to_process.push(previous.clone());
+                    }
+                });
+        }
+
+        visited
+    }
+
+    /// Every best path to a goal, each from the start to the goal. Needs [`Predecessors::All`]
+    /// to be complete. The number of paths can grow exponentially, so this is only meant for
+    /// small graphs.
+    pub fn all_optimal_paths(&self) -> Vec<Vec<S>> {
+        fn collect<S: Clone + Eq + Hash>(
+            predecessors: &HashMap<S, Vec<S>>,
+            suffix: &mut Vec<S>,
+            result: &mut Vec<Vec<S>>,
+        ) {
+            match predecessors.get(suffix.last().expect("not empty")) {
+                Some(previous_states) => previous_states.iter().for_each(|previous| {
+                    suffix.push(previous.clone());
+                    collect(predecessors, suffix, result);
+                    suffix.pop();
+                }),
+                None => result.push(suffix.iter().rev().cloned().collect()),
+            }
+        }
+
+        let mut result = vec![];
+        self.goals.iter().for_each(|goal| {
+            collect(&self.predecessors, &mut vec![goal.clone()], &mut result);
+        });
+        result
+    }
+
+    /// Records that `next` can be reached from `state` at `cost`. Returns whether `next` should
+    /// be (re)visited, which is only the case when its cost improved.
+    fn relax(&mut self, state: &S, next: S, cost: C, predecessors: Predecessors) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.get(&next).map(|known| cost.cmp(known)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => {
+                if predecessors == Predecessors::All {
+                    let previous_states = self.predecessors.entry(next).or_default();
+                    if !previous_states.contains(state) {
+                        previous_states.push(state.clone());
+                    }
+                }
+                false
+            }
+            Some(Ordering::Less) | None => {
+                self.costs.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth first search, where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut to_process = VecDeque::from([(start, 0)]);
+    let mut goal_cost = None;
+
+    while let Some((state, cost)) = to_process.pop_front() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            if predecessors == Predecessors::First {
+                break;
+            }
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if search.relax(&state, next.clone(), cost + 1, predecessors) {
+                to_process.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, where `neighbours` returns each neighbour with the cost to step there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal, predecessors)
+}
+
+/// A* search, where `heuristic` estimates the cost from a state to the nearest goal. The
+/// heuristic must never overestimate, and must be consistent for [`Predecessors::All`].
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    predecessors: Predecessors,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut to_process = BinaryHeap::from([Entry {
+        priority: Reverse(heuristic(&start)),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut goal_cost = None;
+
+    while let Some(Entry {
+        priority: Reverse(priority),
+        cost,
+        state,
+    }) = to_process.pop()
+    {
+        if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+            break;
+        }
+        // outdated entry, the state was reached more cheaply since it was pushed
+        if search.costs[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            if predecessors == Predecessors::First {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step_cost) in neighbours(&state) {
+            let next_cost = cost + step_cost;
+            if search.relax(&state, next.clone(), next_cost, predecessors) {
+                to_process.push(Entry {
+                    priority: Reverse(next_cost + heuristic(&next)),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// Heap entry ordered by priority only, so that states do not need to be `Ord`.
+struct Entry<S, C> {
+    priority: Reverse<C>,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a diamond: 0 -> 1 -> 3 and 0 -> 2 -> 3 cost the same, 0 -> 4 -> 3 costs more
+    fn diamond(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, diamond, |state| *state == 3, Predecessors::First);
+        assert_eq!(search.cost(), Some(2));
+        assert_eq!(search.path().map(|path| path.len()), Some(3));
+
+        let search = dijkstra(0, diamond, |state| *state == 3, Predecessors::All);
+        assert_eq!(
+            search.all_optimal_states(),
+            [0, 1, 2, 3].into_iter().collect()
+        );
+        assert_eq!(search.all_optimal_paths().len(), 2);
+
+        let search = dijkstra(0, diamond, |state| *state == 5, Predecessors::All);
+        assert_eq!(search.cost(), None);
+        assert_eq!(search.path(), None);
+    }
+
+    #[test]
+    fn test_bfs_and_astar_agree() {
+        // walk on a line from 0 to 10, steps of 1 or 3
+        let neighbours = |state: &i32| [*state + 1, *state + 3, *state - 1];
+        let bfs = bfs(0, neighbours, |state| *state == 10, Predecessors::All);
+        let astar = astar(
+            0,
+            |state| neighbours(state).map(|next| (next, 1)),
+            |state| (10 - state).abs() / 3,
+            |state| *state == 10,
+            Predecessors::All,
+        );
+
+        assert_eq!(bfs.cost(), Some(4));
+        assert_eq!(astar.cost(), Some(4));
+        assert_eq!(
+            bfs.all_optimal_paths().len(),
+            astar.all_optimal_paths().len()
+        );
+    }
+}
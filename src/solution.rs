@@ -0,0 +1,243 @@
+use std::ops::Range;
+
+use crate::{
+    animation::{Frame, Replay},
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
+    export::Graph,
+    params::Params,
+    ParseError,
+};
+
+/// A solution of a day. The input is parsed once by [`Solution::parse`], and both parts are
+/// computed from the parsed value.
+pub trait Solution {
+    type Parsed;
+
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+
+    /// The names of the [`Params`] that the day understands.
+    fn params(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Like [`Solution::parse`], with the [`Params`] given to the runner. Only the names listed by
+    /// [`Solution::params`] are accepted by the runner.
+    fn parse_with(&self, input: &str, _params: &Params) -> Result<Self::Parsed, ParseError> {
+        self.parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String;
+
+    fn part2(&self, parsed: &Self::Parsed) -> String;
+
+    /// Day 25 only has one puzzle.
+    fn has_part2(&self) -> bool {
+        true
+    }
+
+    /// Day 17 prints a disassembly of the program for part 2 rather than the answer, so there is
+    /// nothing for `aoc verify` to check.
+    fn prints_part2(&self) -> bool {
+        true
+    }
+
+    /// A graph of the input for `--export`, for the days where one is worth looking at.
+    fn graph(&self, _parsed: &Self::Parsed) -> Option<Graph> {
+        None
+    }
+
+    /// The frames of the simulation at `steps`, for `--animate`, for the days that simulate a grid.
+    fn frames<'a>(
+        &'a self,
+        _parsed: &'a Self::Parsed,
+        _steps: Range<usize>,
+    ) -> Option<Box<dyn Iterator<Item = Frame> + 'a>> {
+        None
+    }
+
+    /// The simulation of the input, for `aoc replay`.
+    fn replay<'a>(&'a self, _parsed: &'a Self::Parsed) -> Option<Box<dyn Replay + 'a>> {
+        None
+    }
+}
+
+/// Object safe version of [`Solution`], so that solutions with different `Parsed` types can be
+/// stored together in the registry.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    fn has_part2(&self) -> bool;
+
+    fn prints_part2(&self) -> bool;
+
+    fn params(&self) -> &'static [&'static str];
+
+    /// Unlike [`Solution::parse`], the error is located in `input` and tagged with the day.
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn DynParsed + 'a>, ParseError> {
+        self.parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(
+        &'a self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Box<dyn DynParsed + 'a>, ParseError>;
+}
+
+/// An input that was parsed by a [`DynSolution`], ready to be solved.
+pub trait DynParsed {
+    fn part1(&self) -> String;
+
+    fn part2(&self) -> String;
+
+    fn graph(&self) -> Option<Graph>;
+
+    fn frames(&self, steps: Range<usize>) -> Option<Box<dyn Iterator<Item = Frame> + '_>>;
+
+    fn replay(&self) -> Option<Box<dyn Replay + '_>>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    parsed: S::Parsed,
+}
+
+impl<S: Solution> DynParsed for Parsed<'_, S> {
+    fn part1(&self) -> String {
+        self.solution.part1(&self.parsed)
+    }
+
+    fn part2(&self) -> String {
+        self.solution.part2(&self.parsed)
+    }
+
+    fn graph(&self) -> Option<Graph> {
+        self.solution.graph(&self.parsed)
+    }
+
+    fn frames(&self, steps: Range<usize>) -> Option<Box<dyn Iterator<Item = Frame> + '_>> {
+        self.solution.frames(&self.parsed, steps)
+    }
+
+    fn replay(&self) -> Option<Box<dyn Replay + '_>> {
+        self.solution.replay(&self.parsed)
+    }
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn has_part2(&self) -> bool {
+        Solution::has_part2(self)
+    }
+
+    fn prints_part2(&self) -> bool {
+        Solution::prints_part2(self)
+    }
+
+    fn params(&self) -> &'static [&'static str] {
+        Solution::params(self)
+    }
+
+    fn parse_with<'a>(
+        &'a self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Box<dyn DynParsed + 'a>, ParseError> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            parsed: Solution::parse_with(self, input, params)
+                .map_err(|err| err.locate(input).in_day(Solution::day(self)))?,
+        }))
+    }
+}
+
+/// All the solutions, ordered by day.
+pub fn solutions() -> [&'static dyn DynSolution; 25] {
+    [
+        &day01::Day01,
+        &day02::Day02,
+        &day03::Day03,
+        &day04::Day04,
+        &day05::Day05,
+        &day06::Day06,
+        &day07::Day07,
+        &day08::Day08,
+        &day09::Day09,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+        &day13::Day13,
+        &day14::Day14,
+        &day15::Day15,
+        &day16::Day16,
+        &day17::Day17,
+        &day18::Day18,
+        &day19::Day19,
+        &day20::Day20,
+        &day21::Day21,
+        &day22::Day22,
+        &day23::Day23,
+        &day24::Day24,
+        &day25::Day25,
+    ]
+}
+
+pub fn solution(day: u8) -> Option<&'static dyn DynSolution> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::Solution;
+
+    pub fn part1<S: Solution>(solution: S, input: &str) -> String {
+        solution.part1(&solution.parse(input).expect("valid input"))
+    }
+
+    pub fn part2<S: Solution>(solution: S, input: &str) -> String {
+        solution.part2(&solution.parse(input).expect("valid input"))
+    }
+
+    /// The error of parsing `input`, with its line and column, e.g.
+    /// `line 2, column 5: expected a number, found "x"`.
+    pub fn parse_error<S: Solution>(solution: S, input: &str) -> String {
+        match solution.parse(input) {
+            Ok(_) => panic!("expected a parse error"),
+            Err(err) => err.locate(input).to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_ordered_by_day() {
+        solutions()
+            .iter()
+            .enumerate()
+            .for_each(|(idx, solution)| assert_eq!(solution.day() as usize, idx + 1));
+        assert!(solution(0).is_none());
+        assert!(solution(26).is_none());
+    }
+
+    #[test]
+    fn test_parse_error_located() {
+        let input = "3   4\r\n4   x\r\n";
+        let err = solution(1).unwrap().parse(input).err().unwrap();
+        assert_eq!(err.day, Some(1));
+        assert_eq!(
+            err.position.map(|position| (position.line, position.col)),
+            Some((2, 5))
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Diagnostics of the solutions, e.g. the picture found by day 14, kept apart from the answers.
+//!
+//! Solutions write to the trace with [`trace!`](crate::trace!), and the runner decides where it
+//! goes with [`set_sink`]: stderr (`aoc run --trace`), a file (`--trace-file <PATH>`) or nowhere,
+//! which is the default. Output that is costly to build should be guarded by [`enabled`].
+
+use std::{
+    fmt::Arguments,
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Sink {
+    #[default]
+    Null,
+    Stderr,
+    /// The file is truncated when the sink is set.
+    File(PathBuf),
+}
+
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+fn writer() -> std::sync::MutexGuard<'static, Option<Box<dyn Write + Send>>> {
+    // a panic while tracing does not leave the writer in a bad state
+    SINK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Routes the trace to `sink`, for the whole process.
+pub fn set_sink(sink: Sink) -> io::Result<()> {
+    let target: Option<Box<dyn Write + Send>> = match sink {
+        Sink::Null => None,
+        Sink::Stderr => Some(Box::new(io::stderr())),
+        Sink::File(path) => Some(Box::new(File::create(path)?)),
+    };
+    *writer() = target;
+    Ok(())
+}
+
+/// Whether the trace goes anywhere.
+pub fn enabled() -> bool {
+    writer().is_some()
+}
+
+/// Writes a line to the trace. Use [`trace!`](crate::trace!) instead.
+#[doc(hidden)]
+pub fn write_line(args: Arguments) {
+    if let Some(writer) = writer().as_mut() {
+        // diagnostics must not make a solution fail, so write errors are dropped
+        let _ = writeln!(writer, "{}", args).and_then(|_| writer.flush());
+    }
+}
+
+/// Writes a line to the trace, with the same arguments as `println!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::trace::write_line(format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_sink() {
+        let path = std::env::temp_dir().join("aoc_2024_trace.txt");
+        set_sink(Sink::File(path.clone())).unwrap();
+        assert!(enabled());
+        crate::trace!("iteration {}", 42);
+        set_sink(Sink::Null).unwrap();
+        assert!(!enabled());
+        crate::trace!("dropped");
+
+        let trace = std::fs::read_to_string(path).unwrap();
+        assert!(trace.lines().any(|line| line == "iteration 42"));
+        assert!(!trace.contains("dropped"));
+    }
+}
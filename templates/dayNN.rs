@@ -0,0 +1,67 @@
+use crate::{parse, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    input.trim().lines().map(parse::number).collect()
+}
+
+fn p1(input: &[u64]) -> String {
+    input.len().to_string()
+}
+
+fn p2(input: &[u64]) -> String {
+    input.len().to_string()
+}
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Parsed = Vec<u64>;
+
+    fn day(&self) -> u8 {
+        0
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> String {
+        p1(parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> String {
+        p2(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::testing::{parse_error, part1, part2};
+
+    const SAMPLE_INPUT: &str = r"
+1
+2
+3
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(part1(DayNN, SAMPLE_INPUT), "3");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(part2(DayNN, SAMPLE_INPUT), "3");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_error(DayNN, "1\nx\n"),
+            "line 2, column 1: expected a number, found \"x\""
+        );
+    }
+
+    crate::answers::actual_tests!(DayNN);
+}